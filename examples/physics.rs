@@ -1,6 +1,7 @@
 /*
 Example displaying the integration with a third party physics engine. In this case Bevy Avian 3d
-The key is to run the CameraSyncSet AFTER the PhysicsSet, see line 14.
+With the `avian3d` feature, the camera interpolates the rigid body it follows and runs after the
+physics systems automatically. See `physics_follow` on the ThirdPersonCamera.
 */
 
 use avian3d::prelude::*;
@@ -9,13 +10,13 @@ use bevy_third_person_camera::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, ThirdPersonCameraPlugin))
+        .add_plugins((
+            DefaultPlugins,
+            PhysicsPlugins::default(),
+            ThirdPersonCameraPlugin,
+        ))
         .add_systems(Startup, (spawn_player, spawn_world, spawn_camera))
         .add_systems(Update, player_movement_keyboard)
-        .configure_sets(
-            PostUpdate,
-            CameraSyncSet.after(PhysicsSystems::StepSimulation),
-        ) // DO THIS!
         .run();
 }
 
@@ -52,6 +53,7 @@ fn spawn_camera(mut commands: Commands) {
             zoom: Zoom::new(1.5, 3.0), // default
             ..default()
         },
+        CameraCollision::default(),
     ));
 }
 
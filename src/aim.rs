@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCamera, ThirdPersonCameraState, ThirdPersonCameraTarget, damping::wrap_angle,
+    input::CameraInput, target_yaw, world_transform,
+};
+
+pub struct AimPlugin;
+
+impl Plugin for AimPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<AimFacing>()
+            .add_systems(Update, aim.run_if(aim_condition));
+    }
+}
+
+/// How the camera zooms in while aiming.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AimMode {
+    /// Shrinks the zoom radius towards `zoom.min * aim_zoom`.
+    #[default]
+    Radius,
+    /// Narrows the field of view to `aim_fov`, leaving the zoom radius intact.
+    /// Requires a perspective Projection.
+    Fov,
+    /// Both shrinks the zoom radius and narrows the field of view.
+    RadiusAndFov,
+}
+
+impl AimMode {
+    fn zooms_radius(self) -> bool {
+        matches!(self, AimMode::Radius | AimMode::RadiusAndFov)
+    }
+
+    fn zooms_fov(self) -> bool {
+        matches!(self, AimMode::Fov | AimMode::RadiusAndFov)
+    }
+}
+
+/// How the target is turned to face the aim direction while aiming. The target is only ever
+/// turned around the y axis, so it stays upright when the camera looks up or down.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{AimRotation, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             aim_enabled: true,
+///             aim_rotation: AimRotation::YawLimited(360.0_f32.to_radians()),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum AimRotation {
+    /// The target is never turned.
+    None,
+    /// The target instantly turns to face the aim direction.
+    #[default]
+    Yaw,
+    /// The target turns towards the aim direction, at most this many radians per second.
+    YawLimited(f32),
+    /// The target's Transform is left untouched and an AimFacing message is written every frame
+    /// instead, e.g. for a character controller or a physics engine that owns the Transform.
+    Message,
+}
+
+/// Written every frame while aiming when `aim_rotation` is AimRotation::Message, with the
+/// direction the target should face.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::AimFacing;
+/// fn face_aim(mut aim_evr: MessageReader<AimFacing>, mut player_q: Query<&mut Transform>) {
+///     for ev in aim_evr.read() {
+///         if let Ok(mut transform) = player_q.get_mut(ev.target) {
+///             transform.rotation = ev.rotation();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Clone, Copy, Debug)]
+pub struct AimFacing {
+    pub camera: Entity,
+    pub target: Entity,
+    /// The desired yaw of the target, in radians.
+    pub yaw: f32,
+    /// The desired horizontal facing of the target.
+    pub direction: Dir3,
+}
+
+impl AimFacing {
+    /// The rotation that makes the target face `direction`.
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.yaw)
+    }
+}
+
+// only run aiming logic if `aim_enabled` is true for at least one camera
+fn aim_condition(cam_q: Query<&ThirdPersonCamera>) -> bool {
+    cam_q.iter().any(|cam| cam.aim_enabled)
+}
+
+fn aim(
+    time: Res<Time>,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    mut aim_evw: MessageWriter<AimFacing>,
+    mut cam_q: Query<(
+        Entity,
+        &mut ThirdPersonCamera,
+        &CameraInput,
+        &mut ThirdPersonCameraState,
+        Option<&mut Projection>,
+    )>,
+    mut transform_q: Query<(&mut Transform, Option<&ChildOf>), Without<ThirdPersonCamera>>,
+) {
+    for (cam_entity, mut cam, input, mut state, projection) in cam_q.iter_mut() {
+        if !cam.aim_enabled {
+            continue;
+        }
+
+        let Some(target) = cam
+            .target_entity(&target_q)
+            .filter(|target| transform_q.contains(*target))
+        else {
+            continue;
+        };
+
+        // blend the field of view & aim offset
+        let step = time.delta_secs() / cam.aim_duration.max(f32::EPSILON);
+        let step = if input.aim { step } else { -step };
+        state.aim_progress = (state.aim_progress + step).clamp(0.0, 1.0);
+        state.aim_blend = cam.aim_ease.sample_clamped(state.aim_progress);
+
+        if cam.aim_mode.zooms_fov()
+            && (state.aim_progress > 0.0 || state.base_fov.is_some())
+            && let Some(mut projection) = projection
+            && let Projection::Perspective(perspective) = &mut *projection
+        {
+            // base_fov is used for restoring the field of view after releasing the aim button
+            let base_fov = *state.base_fov.get_or_insert(perspective.fov);
+            perspective.fov = base_fov + (cam.aim_fov - base_fov) * state.aim_blend;
+            if state.aim_progress <= 0.0 {
+                state.base_fov = None;
+            }
+        }
+
+        // check if aim button was pressed
+        if input.aim {
+            // turn the player or target around the y axis to face the aim direction
+            let yaw = state.yaw;
+            // the yaw is in world space, so it is turned into the target's local space for parented targets
+            let readonly_q = transform_q.as_readonly();
+            let parent_rotation = readonly_q
+                .get(target)
+                .ok()
+                .and_then(|(_, child_of)| child_of)
+                .and_then(|child_of| world_transform(child_of.parent(), &readonly_q))
+                .map_or(Quat::IDENTITY, |parent| parent.rotation());
+            let player = world_transform(target, &readonly_q).unwrap_or_default();
+            let Ok((mut player_transform, _)) = transform_q.get_mut(target) else {
+                continue;
+            };
+            match cam.aim_rotation {
+                AimRotation::None => {}
+                AimRotation::Yaw => {
+                    player_transform.rotation =
+                        parent_rotation.inverse() * Quat::from_rotation_y(yaw);
+                }
+                AimRotation::YawLimited(turn_rate) => {
+                    let current = target_yaw(&player);
+                    let max_step = turn_rate * time.delta_secs();
+                    let step = wrap_angle(yaw - current).clamp(-max_step, max_step);
+                    player_transform.rotation =
+                        parent_rotation.inverse() * Quat::from_rotation_y(current + step);
+                }
+                AimRotation::Message => {
+                    aim_evw.write(AimFacing {
+                        camera: cam_entity,
+                        target,
+                        yaw,
+                        direction: Dir3::new_unchecked(Vec3::new(-yaw.sin(), 0.0, -yaw.cos())),
+                    });
+                }
+            }
+
+            if !cam.aim_mode.zooms_radius() {
+                continue;
+            }
+
+            let desired_zoom = cam.zoom.min * cam.aim_zoom;
+
+            // radius_copy is used for restoring the radius (zoom) to it's
+            // original value after releasing the aim button
+            if cam.zoom.radius_copy.is_none() {
+                cam.zoom.radius_copy = Some(cam.zoom.radius);
+            }
+
+            let zoom_factor =
+                (cam.zoom.radius_copy.unwrap() / cam.aim_zoom) * cam.aim_speed * time.delta_secs();
+
+            // stop zooming in if current radius is less than desired zoom
+            if cam.zoom.radius <= desired_zoom || cam.zoom.radius - zoom_factor <= desired_zoom {
+                cam.zoom.radius = desired_zoom;
+            } else {
+                cam.zoom.radius -= zoom_factor;
+            }
+        } else if let Some(radius_copy) = cam.zoom.radius_copy {
+            let zoom_factor = (radius_copy / cam.aim_zoom) * cam.aim_speed * time.delta_secs();
+
+            // stop zooming out if current radius is greater than original radius
+            if cam.zoom.radius >= radius_copy || cam.zoom.radius + zoom_factor >= radius_copy {
+                cam.zoom.radius = radius_copy;
+                cam.zoom.radius_copy = None;
+            } else {
+                cam.zoom.radius += (radius_copy / cam.aim_zoom) * cam.aim_speed * time.delta_secs();
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+
+use crate::time::CameraTime;
+
+use crate::{
+    CameraFadeable, CameraOcclusionProvider, OcclusionCast, ThirdPersonCamera,
+    ThirdPersonCameraState, ThirdPersonCameraTarget, world_transform,
+};
+
+/// Keeps the camera from clipping through geometry. A sphere is cast from the camera target to
+/// the desired camera position, and the camera is pulled in to the first hit.
+/// Requires a CameraOcclusionProvider, such as the one added with the `avian3d` feature.
+/// CameraFadeable entities fade out instead of pulling the camera in.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{CameraCollision, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera::default(),
+///         CameraCollision::new(0.2).with_recovery_speed(5.0),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct CameraCollision {
+    /// The radius of the sphere that is cast from the target to the camera.
+    /// Larger values keep the camera further away from walls.
+    /// Default is 0.2
+    pub probe_radius: f32,
+    /// The speed at which the camera moves back out to the desired radius once the obstruction clears.
+    /// Default is 5.0
+    pub recovery_speed: f32,
+    distance: Option<f32>,
+}
+
+impl Default for CameraCollision {
+    fn default() -> Self {
+        Self::new(0.2)
+    }
+}
+
+impl CameraCollision {
+    pub fn new(probe_radius: f32) -> Self {
+        Self {
+            probe_radius,
+            recovery_speed: 5.0,
+            distance: None,
+        }
+    }
+
+    pub fn with_recovery_speed(mut self, recovery_speed: f32) -> Self {
+        self.recovery_speed = recovery_speed;
+        self
+    }
+}
+
+pub(crate) fn avoid_collisions<P: CameraOcclusionProvider>(
+    time: CameraTime,
+    mut occlusion: StaticSystemParam<P::Param>,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    transform_q: Query<(&Transform, Option<&ChildOf>), Without<ThirdPersonCamera>>,
+    mut cam_q: Query<(
+        Entity,
+        &ThirdPersonCamera,
+        &ThirdPersonCameraState,
+        &mut CameraCollision,
+        &mut Transform,
+    )>,
+    cam_parent_q: Query<&ChildOf, With<ThirdPersonCamera>>,
+    fadeable_q: Query<Entity, With<CameraFadeable>>,
+) {
+    // fadeables fade out instead of blocking the view, the first entry is each camera's target
+    let mut excluded: Vec<Entity> = std::iter::once(Entity::PLACEHOLDER)
+        .chain(fadeable_q.iter())
+        .collect();
+
+    for (cam_entity, cam, state, mut collision, mut cam_transform) in cam_q.iter_mut() {
+        // a free flying camera passes through everything
+        if state.free_fly {
+            collision.distance = None;
+            continue;
+        }
+        let Some(target) = cam
+            .target_entity(&target_q)
+            .filter(|t| transform_q.contains(*t))
+        else {
+            continue;
+        };
+
+        // the cast is in world space, so a parented camera is moved in & out of its parent's space
+        let parent = cam_parent_q
+            .get(cam_entity)
+            .ok()
+            .and_then(|child_of| world_transform(child_of.parent(), &transform_q))
+            .unwrap_or_default();
+
+        // cast from the target rather than the look-ahead point, which may be inside a wall
+        let pivot = state.pivot - state.look_ahead;
+        let arm = parent.transform_point(cam_transform.translation) - pivot;
+        let Ok(direction) = Dir3::new(arm) else {
+            continue;
+        };
+        let desired_distance = arm.length();
+
+        excluded[0] = target;
+
+        // the closest distance the camera can be placed at without being obstructed
+        let cast = OcclusionCast {
+            camera: cam_entity,
+            origin: pivot,
+            direction,
+            max_distance: desired_distance,
+            radius: collision.probe_radius,
+            excluded: &excluded,
+        };
+        let allowed_distance = P::cast(&mut occlusion, &cast).unwrap_or(desired_distance);
+
+        // snap in when obstructed, ease back out once the obstruction clears
+        let distance = match collision.distance {
+            Some(distance) if distance < allowed_distance => {
+                (distance + collision.recovery_speed * time.delta_secs(state)).min(allowed_distance)
+            }
+            _ => allowed_distance,
+        };
+        collision.distance = Some(distance);
+
+        cam_transform.translation = parent
+            .affine()
+            .inverse()
+            .transform_point3(pivot + direction * distance);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    f32::consts::{PI, TAU},
+    ops::{Add, Mul, Sub},
+};
+
+/// The smoothing model used when the camera catches up with its desired state.
+/// All models are frame-rate independent.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Damping {
+    /// No smoothing. The camera snaps to its desired state every frame.
+    #[default]
+    None,
+    /// Exponential decay towards the desired state. The value is the decay rate,
+    /// the higher the value, the snappier the camera.
+    /// Example: Damping::Exponential(10.0)
+    Exponential(f32),
+    /// Critically damped spring. The value is roughly the time in seconds it takes to catch up
+    /// with the desired state. Unlike exponential decay, the camera eases in and out of motion.
+    /// Example: Damping::Spring(0.15)
+    Spring(f32),
+}
+
+impl Damping {
+    /// Moves `current` towards `target`. `velocity` is only used by the spring model and
+    /// must be kept between frames.
+    pub fn smooth<T>(&self, current: T, target: T, velocity: &mut T, delta_secs: f32) -> T
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+    {
+        match *self {
+            Damping::None => target,
+            Damping::Exponential(decay_rate) => {
+                target + (current - target) * (-decay_rate * delta_secs).exp()
+            }
+            Damping::Spring(smooth_time) => {
+                if smooth_time <= 0.0 {
+                    return target;
+                }
+                // https://archive.org/details/game-programming-gems-4 (chapter 1.10)
+                let omega = 2.0 / smooth_time;
+                let x = omega * delta_secs;
+                let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+                let change = current - target;
+                let temp = (*velocity + change * omega) * delta_secs;
+                *velocity = (*velocity - temp * omega) * exp;
+                target + (change + temp) * exp
+            }
+        }
+    }
+
+    /// Same as `smooth`, but takes the shortest path around the circle.
+    pub fn smooth_angle(
+        &self,
+        current: f32,
+        target: f32,
+        velocity: &mut f32,
+        delta_secs: f32,
+    ) -> f32 {
+        let target = current + wrap_angle(target - current);
+        self.smooth(current, target, velocity, delta_secs)
+    }
+}
+
+/// Follow damping of the camera, configured separately for each part of the camera's motion.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{Damping, FollowDamping, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             damping: FollowDamping {
+///                 position: Damping::Spring(0.1),
+///                 rotation: Damping::Exponential(20.0),
+///                 zoom: Damping::Exponential(10.0),
+///             },
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FollowDamping {
+    /// Smoothing of the point the camera orbits around as the target moves (follow lag).
+    /// Default is Damping::None
+    pub position: Damping,
+    /// Smoothing of the orbit rotation.
+    /// Default is Damping::None
+    pub rotation: Damping,
+    /// Smoothing of the zoom radius.
+    /// Default is Damping::Exponential(12.0)
+    pub zoom: Damping,
+}
+
+impl Default for FollowDamping {
+    fn default() -> Self {
+        Self {
+            position: Damping::None,
+            rotation: Damping::None,
+            zoom: Damping::Exponential(12.0),
+        }
+    }
+}
+
+/// Wraps an angle in radians to the range [-PI, PI)
+pub(crate) fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // steps a damping model towards the target at a fixed frame rate
+    fn run(damping: Damping, from: f32, to: f32, secs: f32, fps: f32) -> f32 {
+        let (mut value, mut velocity) = (from, 0.0);
+        for _ in 0..(secs * fps).round() as usize {
+            value = damping.smooth(value, to, &mut velocity, 1.0 / fps);
+        }
+        value
+    }
+
+    #[test]
+    fn none_snaps_to_target() {
+        assert_eq!(Damping::None.smooth(0.0, 5.0, &mut 0.0, 0.016), 5.0);
+    }
+
+    #[test]
+    fn exponential_is_frame_rate_independent() {
+        let damping = Damping::Exponential(4.0);
+        let slow = run(damping, 0.0, 10.0, 0.5, 30.0);
+        let fast = run(damping, 0.0, 10.0, 0.5, 240.0);
+        assert!((slow - fast).abs() < 1e-3);
+        assert!((slow - 10.0 * (1.0 - (-2.0_f32).exp())).abs() < 1e-3);
+    }
+
+    #[test]
+    fn spring_settles_without_overshooting() {
+        let damping = Damping::Spring(0.2);
+        let (mut value, mut velocity) = (0.0, 0.0);
+        for _ in 0..120 {
+            value = damping.smooth(value, 1.0, &mut velocity, 1.0 / 60.0);
+            assert!(value <= 1.0 + 1e-5);
+        }
+        assert!((value - 1.0).abs() < 1e-3);
+        assert!(velocity.abs() < 1e-2);
+    }
+
+    #[test]
+    fn spring_is_frame_rate_independent() {
+        let damping = Damping::Spring(0.3);
+        let slow = run(damping, 0.0, 1.0, 0.3, 30.0);
+        let fast = run(damping, 0.0, 1.0, 0.3, 240.0);
+        assert!((slow - fast).abs() < 1e-2);
+    }
+
+    #[test]
+    fn spring_with_zero_time_snaps() {
+        assert_eq!(Damping::Spring(0.0).smooth(0.0, 3.0, &mut 1.0, 0.016), 3.0);
+    }
+
+    #[test]
+    fn smooth_angle_takes_the_short_way_around() {
+        let angle = Damping::Exponential(10.0).smooth_angle(3.0, -3.0, &mut 0.0, 0.016);
+        assert!(angle > 3.0);
+    }
+
+    #[test]
+    fn wrap_angle_stays_within_half_turns() {
+        assert!((wrap_angle(TAU + 0.5) - 0.5).abs() < 1e-5);
+        assert!((wrap_angle(-TAU - 0.5) + 0.5).abs() < 1e-5);
+        assert!((wrap_angle(PI) + PI).abs() < 1e-5);
+        assert_eq!(wrap_angle(0.0), 0.0);
+    }
+}
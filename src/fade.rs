@@ -0,0 +1,356 @@
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+
+use crate::{
+    CameraCollision, CameraOcclusionProvider, CameraSyncSystems, OcclusionCast, ThirdPersonCamera,
+    ThirdPersonCameraState, ThirdPersonCameraTarget, time::CameraTime, world_transform,
+};
+
+pub struct FadePlugin;
+
+impl Plugin for FadePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                hide_near_target.in_set(CameraSyncSystems::Fade),
+                update_fades.after(CameraSyncSystems::Effects),
+            ),
+        );
+
+        #[cfg(feature = "standard_material")]
+        app.add_systems(PostUpdate, fade_standard_materials.after(update_fades));
+    }
+}
+
+/// Fades the entity out while it blocks the view between a camera and its target, instead of
+/// pulling the camera in front of it. The entity is ignored by camera collision, and any hit on
+/// a descendant fades the whole entity, e.g. the meshes of a scene.
+/// Requires a CameraOcclusionProvider, such as AvianOcclusion or MeshOcclusion.
+///
+/// The fade is tracked in the CameraFade component. With the `standard_material` feature, the
+/// alpha of StandardMaterials is faded automatically; otherwise read CameraFade to fade your own materials.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::CameraFadeable;
+/// fn spawn_tree(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         SceneRoot(asset_server.load("tree.glb#Scene0")),
+///         CameraFadeable {
+///             alpha: 0.3,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+#[require(CameraFade)]
+pub struct CameraFadeable {
+    /// The alpha the entity fades to while it blocks the view.
+    /// Default is 0.2
+    pub alpha: f32,
+    /// How fast the entity fades out once it blocks the view, in alpha per second.
+    /// Default is 4.0
+    pub fade_out_speed: f32,
+    /// How fast the entity fades back in once the view clears, in alpha per second.
+    /// Default is 2.0
+    pub fade_in_speed: f32,
+}
+
+impl Default for CameraFadeable {
+    fn default() -> Self {
+        Self {
+            alpha: 0.2,
+            fade_out_speed: 4.0,
+            fade_in_speed: 2.0,
+        }
+    }
+}
+
+/// The current fade of a CameraFadeable entity. Added automatically, and only changed when the
+/// alpha changes, so `Changed<CameraFade>` can be used to update materials.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct CameraFade {
+    alpha: f32,
+    occluding: bool,
+}
+
+impl Default for CameraFade {
+    fn default() -> Self {
+        Self {
+            alpha: 1.0,
+            occluding: false,
+        }
+    }
+}
+
+impl CameraFade {
+    /// The current alpha, from the CameraFadeable alpha when fully faded out to 1.0 when fully visible.
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Whether the entity currently blocks the view of any camera.
+    pub fn is_occluding(&self) -> bool {
+        self.occluding
+    }
+}
+
+pub(crate) fn find_occluders<P: CameraOcclusionProvider>(
+    mut occlusion: StaticSystemParam<P::Param>,
+    mut occluders: Local<Vec<Entity>>,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    transform_q: Query<(&Transform, Option<&ChildOf>), Without<ThirdPersonCamera>>,
+    cam_q: Query<(
+        Entity,
+        &ThirdPersonCamera,
+        &ThirdPersonCameraState,
+        &Transform,
+        Option<&CameraCollision>,
+    )>,
+    parent_q: Query<&ChildOf>,
+    mut fade_q: Query<(Entity, &mut CameraFade), With<CameraFadeable>>,
+) {
+    if fade_q.is_empty() {
+        return;
+    }
+
+    occluders.clear();
+    for (cam_entity, cam, state, cam_transform, collision) in cam_q.iter() {
+        let Some(target) = cam.target_entity(&target_q).filter(|_| !state.free_fly) else {
+            continue;
+        };
+
+        let parent = parent_q
+            .get(cam_entity)
+            .ok()
+            .and_then(|child_of| world_transform(child_of.parent(), &transform_q))
+            .unwrap_or_default();
+
+        // everything between the target and the camera, cast the same way as camera collision
+        let pivot = state.pivot - state.look_ahead;
+        let arm = parent.transform_point(cam_transform.translation) - pivot;
+        let Ok(direction) = Dir3::new(arm) else {
+            continue;
+        };
+        let cast = OcclusionCast {
+            camera: cam_entity,
+            origin: pivot,
+            direction,
+            max_distance: arm.length(),
+            radius: collision.map_or(0.0, |collision| collision.probe_radius),
+            excluded: &[target],
+        };
+
+        P::cast_all(&mut occlusion, &cast, &mut occluders);
+    }
+
+    // a hit on a descendant fades its CameraFadeable ancestor
+    occluders.retain_mut(|hit| {
+        let fadeable = std::iter::once(*hit)
+            .chain(parent_q.iter_ancestors(*hit))
+            .find(|entity| fade_q.contains(*entity));
+        fadeable.map(|fadeable| *hit = fadeable).is_some()
+    });
+
+    for (entity, mut fade) in fade_q.iter_mut() {
+        let occluding = occluders.contains(&entity);
+        if fade.occluding != occluding {
+            fade.occluding = occluding;
+        }
+    }
+}
+
+/// What happens to the target when the camera comes closer to it than a given distance, measured
+/// from the camera to the pivot after collision.
+///
+/// Hiding & fading are global, not per camera: every camera stops seeing the target. Only use it
+/// when a single camera views the target.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{NearTarget, ThirdPersonCamera};
+/// let cam = ThirdPersonCamera {
+///     // fully visible at 1.0, fully transparent at 0.4
+///     near_target: NearTarget::Fade { start: 1.0, end: 0.4 },
+///     ..default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum NearTarget {
+    /// The target is always visible.
+    #[default]
+    Off,
+    /// Sets the target's Visibility to hidden below the distance, restoring it once the camera moves back out.
+    /// This hides the target from every camera.
+    Hide { distance: f32 },
+    /// Fades the target's CameraFade alpha from 1.0 at the `start` distance to 0.0 at the `end`
+    /// distance. The alpha is applied to StandardMaterials with the `standard_material` feature,
+    /// otherwise read CameraFade to fade your own materials. Don't combine with CameraFadeable on the target.
+    Fade { start: f32, end: f32 },
+}
+
+fn hide_near_target(
+    mut commands: Commands,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    transform_q: Query<(&Transform, Option<&ChildOf>), Without<ThirdPersonCamera>>,
+    mut cam_q: Query<(
+        Entity,
+        &ThirdPersonCamera,
+        &mut ThirdPersonCameraState,
+        &Transform,
+    )>,
+    parent_q: Query<&ChildOf>,
+    mut visibility_q: Query<&mut Visibility>,
+    mut fade_q: Query<&mut CameraFade>,
+) {
+    for (cam_entity, cam, mut state, cam_transform) in cam_q.iter_mut() {
+        // a free flying camera doesn't follow the target, so it's restored as if out of range
+        let target = cam.target_entity(&target_q).filter(|_| !state.free_fly);
+        let parent = parent_q
+            .get(cam_entity)
+            .ok()
+            .and_then(|child_of| world_transform(child_of.parent(), &transform_q))
+            .unwrap_or_default();
+        let distance = parent
+            .transform_point(cam_transform.translation)
+            .distance(state.pivot - state.look_ahead);
+
+        let hide = match (cam.near_target, target) {
+            (
+                NearTarget::Hide {
+                    distance: hide_distance,
+                },
+                Some(target),
+            ) => (distance < hide_distance).then_some(target),
+            _ => None,
+        };
+        let alpha = match (cam.near_target, target) {
+            (NearTarget::Fade { start, end }, Some(target)) => {
+                let alpha = ((distance - end) / (start - end).max(f32::EPSILON)).clamp(0.0, 1.0);
+                (alpha < 1.0).then_some((target, alpha))
+            }
+            _ => None,
+        };
+
+        // restore the previous target once the camera moves back out, or the target or policy changes
+        if let Some((hidden, visibility)) = state.near_hidden
+            && hide != Some(hidden)
+        {
+            if let Ok(mut hidden_visibility) = visibility_q.get_mut(hidden) {
+                *hidden_visibility = visibility;
+            }
+            state.near_hidden = None;
+        }
+        if let Some(faded) = state.near_faded
+            && alpha.map(|(target, _)| target) != Some(faded)
+        {
+            if let Ok(mut fade) = fade_q.get_mut(faded) {
+                fade.alpha = 1.0;
+            }
+            state.near_faded = None;
+        }
+
+        if let Some(target) = hide
+            && state.near_hidden.is_none()
+            && let Ok(mut visibility) = visibility_q.get_mut(target)
+        {
+            state.near_hidden = Some((target, *visibility));
+            *visibility = Visibility::Hidden;
+        }
+
+        if let Some((target, alpha)) = alpha {
+            state.near_faded = Some(target);
+            match fade_q.get_mut(target) {
+                Ok(mut fade) if fade.alpha != alpha => fade.alpha = alpha,
+                Ok(_) => {}
+                Err(_) => {
+                    commands.entity(target).insert(CameraFade {
+                        alpha,
+                        occluding: false,
+                    });
+                }
+            }
+        }
+    }
+}
+
+// moves the alpha of every fadeable entity towards its target alpha
+fn update_fades(
+    time: CameraTime,
+    state_q: Query<&ThirdPersonCameraState>,
+    mut fade_q: Query<(&CameraFadeable, &mut CameraFade)>,
+) {
+    let dt = time.shared_delta_secs(state_q.iter());
+    for (fadeable, mut fade) in fade_q.iter_mut() {
+        let alpha = if fade.occluding {
+            (fade.alpha - fadeable.fade_out_speed * dt).max(fadeable.alpha)
+        } else {
+            (fade.alpha + fadeable.fade_in_speed * dt).min(1.0)
+        };
+        if fade.alpha != alpha {
+            fade.alpha = alpha;
+        }
+    }
+}
+
+// the shared material of a mesh, while the mesh uses its own faded copy
+#[cfg(feature = "standard_material")]
+#[derive(Component)]
+struct FadedMaterial(Handle<StandardMaterial>);
+
+// gives faded meshes their own copy of their material, and restores the original once fully visible
+#[cfg(feature = "standard_material")]
+fn fade_standard_materials(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    fade_q: Query<(Entity, &CameraFade), Changed<CameraFade>>,
+    children_q: Query<&Children>,
+    mut mesh_q: Query<(
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&FadedMaterial>,
+    )>,
+) {
+    for (entity, fade) in fade_q.iter() {
+        for mesh in std::iter::once(entity).chain(children_q.iter_descendants(entity)) {
+            let Ok((mut material, faded)) = mesh_q.get_mut(mesh) else {
+                continue;
+            };
+
+            if fade.alpha >= 1.0 {
+                if let Some(FadedMaterial(original)) = faded {
+                    material.0 = original.clone();
+                    commands.entity(mesh).remove::<FadedMaterial>();
+                }
+                continue;
+            }
+
+            let original = match faded {
+                Some(FadedMaterial(original)) => original.clone(),
+                None => {
+                    let Some(copy) = materials.get(&material.0).cloned() else {
+                        continue;
+                    };
+                    let original = std::mem::replace(&mut material.0, materials.add(copy));
+                    commands
+                        .entity(mesh)
+                        .insert(FadedMaterial(original.clone()));
+                    original
+                }
+            };
+
+            let Some(original_alpha) = materials.get(&original).map(|m| m.base_color.alpha())
+            else {
+                continue;
+            };
+            if let Some(faded) = materials.get_mut(&material.0) {
+                faded.base_color.set_alpha(original_alpha * fade.alpha);
+                faded.alpha_mode = AlphaMode::Blend;
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::{TargetOffset, ThirdPersonCameraState};
+
+/// A first person view at the target's eye point, entered by zooming in past `zoom.min` and left
+/// by zooming back out. While in first person, the pitch & yaw limits and the offset are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{FirstPerson, TargetOffset, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             first_person: Some(FirstPerson {
+///                 eye_offset: TargetOffset::Local(Vec3::new(0.0, 1.7, -0.1)),
+///                 ..default()
+///             }),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FirstPerson {
+    /// The eye point, relative to the target. Ignored when `eye` is set.
+    /// Default is TargetOffset::Local(Vec3::new(0.0, 1.6, 0.0))
+    pub eye_offset: TargetOffset,
+    /// An entity used as the eye point instead of `eye_offset`, e.g. the head bone of an animated character.
+    /// Default is None
+    pub eye: Option<Entity>,
+    /// The time in seconds it takes to blend into and out of first person.
+    /// Default is 0.3
+    pub duration: f32,
+    /// Default is EaseFunction::CubicInOut
+    pub ease: EaseFunction,
+}
+
+impl Default for FirstPerson {
+    fn default() -> Self {
+        Self {
+            eye_offset: TargetOffset::Local(Vec3::new(0.0, 1.6, 0.0)),
+            eye: None,
+            duration: 0.3,
+            ease: EaseFunction::CubicInOut,
+        }
+    }
+}
+
+impl ThirdPersonCameraState {
+    /// Blends the camera between the orbit and the eye point, looking in the orbit direction.
+    /// The eye point is only looked up while in, or blending into, first person.
+    pub(crate) fn blend_first_person(
+        &mut self,
+        first_person: Option<&FirstPerson>,
+        eye: impl FnOnce(&FirstPerson) -> Vec3,
+        rotation: Quat,
+        dt: f32,
+        world: &mut Transform,
+    ) {
+        let Some(first_person) = first_person else {
+            self.first_person = false;
+            self.first_person_progress = 0.0;
+            return;
+        };
+
+        let step = dt / first_person.duration.max(f32::EPSILON);
+        let step = if self.first_person { step } else { -step };
+        self.first_person_progress = (self.first_person_progress + step).clamp(0.0, 1.0);
+
+        if self.first_person_progress > 0.0 {
+            let eye = eye(first_person);
+            let t = first_person.ease.sample_clamped(self.first_person_progress);
+            world.translation = world.translation.lerp(eye, t);
+            world.rotation = world.rotation.slerp(rotation, t);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCamera, ThirdPersonCameraState, input::CameraInput, orbit::orbit, time::CameraTime,
+};
+
+pub struct FreeFlyPlugin;
+
+impl Plugin for FreeFlyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, free_fly.after(orbit).run_if(free_fly_condition));
+    }
+}
+
+// toggles free fly, and moves free flying cameras in the direction they are looking
+fn free_fly(
+    time: CameraTime,
+    mut cam_q: Query<(
+        &ThirdPersonCamera,
+        &CameraInput,
+        &mut ThirdPersonCameraState,
+        &GlobalTransform,
+    )>,
+) {
+    for (cam, input, mut state, cam_global) in cam_q.iter_mut() {
+        if input.free_fly && !state.is_in_photo_mode() {
+            let free_fly = !state.free_fly;
+            state.set_free_fly(free_fly);
+        }
+        if !state.free_fly {
+            continue;
+        }
+
+        // start flying from where the camera is
+        let position = *state
+            .fly_position
+            .get_or_insert_with(|| cam_global.translation());
+
+        let rate = input.fly_rate;
+        let movement = state.rotation() * Vec3::new(rate.x, 0.0, -rate.z) + Vec3::Y * rate.y;
+        state.fly_position =
+            Some(position + movement * cam.free_fly_speed * time.delta_secs(&state));
+    }
+}
+
+// only run free fly logic if at least one camera can, or already does, fly freely
+fn free_fly_condition(cam_q: Query<(&ThirdPersonCamera, &ThirdPersonCameraState)>) -> bool {
+    cam_q
+        .iter()
+        .any(|(cam, state)| cam.free_fly_enabled || state.free_fly)
+}
@@ -0,0 +1,606 @@
+use bevy::{
+    input::{
+        InputSystems,
+        mouse::{MouseMotion, MouseWheel},
+    },
+    prelude::*,
+};
+use bevy_unified_input::InputBinding;
+
+use crate::{CustomGamepadSettings, ThirdPersonCamera, ThirdPersonCameraState};
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, read_camera_input.after(InputSystems));
+    }
+}
+
+/// The camera actions and the inputs bound to them. Button actions accept any mix of keys,
+/// mouse buttons and gamepad buttons. Analog actions accept any mix of mouse, gamepad axes
+/// and buttons.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{AxisInput, CameraActionMap, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             actions: CameraActionMap {
+///                 aim: [MouseButton::Right.into(), KeyCode::KeyQ.into()].into(),
+///                 zoom: vec![
+///                     AxisInput::MouseWheel,
+///                     AxisInput::Buttons(KeyCode::Minus.into(), KeyCode::Equal.into()),
+///                 ],
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CameraActionMap {
+    /// Orbits the camera around its target.
+    /// Default is mouse motion & the right stick
+    pub orbit: Vec<DualAxisInput>,
+    /// Zooms the camera in (positive values) and out (negative values).
+    /// Default is the scroll wheel & D Pad up/down
+    pub zoom: Vec<AxisInput>,
+    /// Cycles through the zoom steps, see `Zoom::with_steps`.
+    /// Default is KeyCode::KeyV & GamepadButton::Select
+    pub zoom_step: InputBinding,
+    /// Aims while active, see `aim_activation`.
+    /// Default is MouseButton::Right & GamepadButton::LeftTrigger2
+    pub aim: InputBinding,
+    /// How the aim action is activated.
+    /// Default is ActivationMode::Hold
+    pub aim_activation: ActivationMode,
+    /// Orbiting only occurs while active, when `mouse_orbit_button_enabled` is true.
+    /// Default is MouseButton::Middle & GamepadButton::LeftTrigger
+    pub orbit_button: InputBinding,
+    /// How the orbit button is activated.
+    /// Default is ActivationMode::Hold
+    pub orbit_button_activation: ActivationMode,
+    /// Inverts the x offset, swapping the camera to the other shoulder.
+    /// Default is KeyCode::KeyE & GamepadButton::North
+    pub shoulder_swap: InputBinding,
+    /// Toggles the cursor lock.
+    /// Default is KeyCode::Space
+    pub cursor_toggle: InputBinding,
+    /// How the cursor toggle is activated. With ActivationMode::Hold the cursor lock is flipped
+    /// while held, e.g. to free the cursor only while a key is held down.
+    /// Default is ActivationMode::Toggle
+    pub cursor_toggle_activation: ActivationMode,
+    /// Swings the camera back behind the target.
+    /// Default is KeyCode::KeyR & GamepadButton::RightThumb
+    pub recenter: InputBinding,
+    /// Locks on to the LockOnTarget closest to the center of the screen, or releases the lock.
+    /// Default is KeyCode::KeyF & GamepadButton::LeftThumb
+    pub lock_on: InputBinding,
+    /// Switches the lock to the next LockOnTarget to the right.
+    /// Default is KeyCode::KeyX & GamepadButton::DPadRight
+    pub lock_on_next: InputBinding,
+    /// Switches the lock to the next LockOnTarget to the left.
+    /// Default is KeyCode::KeyZ & GamepadButton::DPadLeft
+    pub lock_on_previous: InputBinding,
+    /// Detaches the camera from its target to fly freely, or snaps it back to orbit the target.
+    /// Only used when `free_fly_enabled` is true.
+    /// Default is KeyCode::F1
+    pub free_fly: InputBinding,
+    /// Moves a free flying camera, with x to the right & y forward.
+    /// Default is W/A/S/D & the left stick
+    pub fly: Vec<DualAxisInput>,
+    /// Moves a free flying camera down (negative values) and up (positive values).
+    /// Default is Q/E & D Pad down/up
+    pub fly_vertical: Vec<AxisInput>,
+    /// Enters or leaves photo mode. Only used when `photo_mode.enabled` is true.
+    /// Default is KeyCode::KeyP
+    pub photo_mode: InputBinding,
+    /// Rolls the camera in photo mode, counterclockwise (negative values) or clockwise (positive values).
+    /// Default is Z/C & the west/east face buttons
+    pub roll: Vec<AxisInput>,
+    /// Narrows (positive values) or widens (negative values) the field of view in photo mode.
+    /// Default is -/= & the triggers
+    pub fov: Vec<AxisInput>,
+    /// Moves the focus further away (positive values) or closer (negative values) in photo mode.
+    /// Default is [/] & D Pad left/right
+    pub focus: Vec<AxisInput>,
+    /// Raises (positive values) or lowers (negative values) the aperture f-stops in photo mode,
+    /// narrowing or widening the depth of field.
+    /// Default is ,/.
+    pub aperture: Vec<AxisInput>,
+    /// The time in seconds between two presses for them to count as a double tap.
+    /// Default is 0.3
+    pub double_tap_window: f32,
+}
+
+impl Default for CameraActionMap {
+    fn default() -> Self {
+        Self {
+            orbit: vec![
+                DualAxisInput::MouseMotion,
+                DualAxisInput::GamepadStick(GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+            ],
+            zoom: vec![
+                AxisInput::MouseWheel,
+                AxisInput::Buttons(GamepadButton::DPadDown.into(), GamepadButton::DPadUp.into()),
+            ],
+            zoom_step: [KeyCode::KeyV.into(), GamepadButton::Select.into()].into(),
+            aim: [
+                MouseButton::Right.into(),
+                GamepadButton::LeftTrigger2.into(),
+            ]
+            .into(),
+            aim_activation: ActivationMode::Hold,
+            orbit_button: [
+                MouseButton::Middle.into(),
+                GamepadButton::LeftTrigger.into(),
+            ]
+            .into(),
+            orbit_button_activation: ActivationMode::Hold,
+            shoulder_swap: [KeyCode::KeyE.into(), GamepadButton::North.into()].into(),
+            cursor_toggle: KeyCode::Space.into(),
+            cursor_toggle_activation: ActivationMode::Toggle,
+            recenter: [KeyCode::KeyR.into(), GamepadButton::RightThumb.into()].into(),
+            lock_on: [KeyCode::KeyF.into(), GamepadButton::LeftThumb.into()].into(),
+            lock_on_next: [KeyCode::KeyX.into(), GamepadButton::DPadRight.into()].into(),
+            lock_on_previous: [KeyCode::KeyZ.into(), GamepadButton::DPadLeft.into()].into(),
+            free_fly: KeyCode::F1.into(),
+            fly: vec![
+                DualAxisInput::Buttons(
+                    KeyCode::KeyA.into(),
+                    KeyCode::KeyD.into(),
+                    KeyCode::KeyS.into(),
+                    KeyCode::KeyW.into(),
+                ),
+                DualAxisInput::GamepadStick(GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+            ],
+            fly_vertical: vec![
+                AxisInput::Buttons(KeyCode::KeyQ.into(), KeyCode::KeyE.into()),
+                AxisInput::Buttons(GamepadButton::DPadDown.into(), GamepadButton::DPadUp.into()),
+            ],
+            photo_mode: KeyCode::KeyP.into(),
+            roll: vec![
+                AxisInput::Buttons(KeyCode::KeyZ.into(), KeyCode::KeyC.into()),
+                AxisInput::Buttons(GamepadButton::West.into(), GamepadButton::East.into()),
+            ],
+            fov: vec![
+                AxisInput::Buttons(KeyCode::Minus.into(), KeyCode::Equal.into()),
+                AxisInput::Buttons(
+                    GamepadButton::LeftTrigger2.into(),
+                    GamepadButton::RightTrigger2.into(),
+                ),
+            ],
+            focus: vec![
+                AxisInput::Buttons(KeyCode::BracketLeft.into(), KeyCode::BracketRight.into()),
+                AxisInput::Buttons(
+                    GamepadButton::DPadLeft.into(),
+                    GamepadButton::DPadRight.into(),
+                ),
+            ],
+            aperture: vec![AxisInput::Buttons(
+                KeyCode::Comma.into(),
+                KeyCode::Period.into(),
+            )],
+            double_tap_window: 0.3,
+        }
+    }
+}
+
+/// How a button action is activated. Applies the same way to keys, mouse buttons and gamepad buttons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ActivationMode {
+    /// Active while held.
+    #[default]
+    Hold,
+    /// Each press switches the action on or off.
+    Toggle,
+    /// Two presses within `double_tap_window` switch the action on or off.
+    DoubleTap,
+}
+
+// whether a button action is active, based on its activation mode
+#[derive(Default, Debug)]
+struct ActionState {
+    active: bool,
+    // elapsed time of the last press that could start a double tap
+    last_press: Option<f32>,
+}
+
+impl ActionState {
+    // returns true when the action was switched on or off
+    fn update(
+        &mut self,
+        mode: ActivationMode,
+        pressed: bool,
+        just_pressed: bool,
+        now: f32,
+        double_tap_window: f32,
+    ) -> bool {
+        let was_active = self.active;
+        match mode {
+            ActivationMode::Hold => self.active = pressed,
+            ActivationMode::Toggle => self.active ^= just_pressed,
+            ActivationMode::DoubleTap if just_pressed => match self.last_press {
+                Some(last) if now - last <= double_tap_window => {
+                    self.active = !self.active;
+                    self.last_press = None;
+                }
+                _ => self.last_press = Some(now),
+            },
+            ActivationMode::DoubleTap => {}
+        }
+        self.active != was_active
+    }
+}
+
+// the activation state of every button action that supports an activation mode
+#[derive(Default, Debug)]
+struct ActivationStates {
+    aim: ActionState,
+    orbit_button: ActionState,
+    cursor_toggle: ActionState,
+}
+
+/// An input that drives a two dimensional camera action, such as orbiting.
+#[derive(Clone, Debug)]
+pub enum DualAxisInput {
+    /// Mouse movement.
+    MouseMotion,
+    /// A gamepad stick, given by its x & y axes.
+    GamepadStick(GamepadAxis, GamepadAxis),
+    /// Four buttons acting as a stick: left, right, down & up.
+    Buttons(InputBinding, InputBinding, InputBinding, InputBinding),
+}
+
+/// An input that drives a one dimensional camera action, such as zooming.
+#[derive(Clone, Debug)]
+pub enum AxisInput {
+    /// The scroll wheel.
+    MouseWheel,
+    /// A single gamepad axis.
+    GamepadAxis(GamepadAxis),
+    /// Two buttons acting as an axis: negative & positive.
+    Buttons(InputBinding, InputBinding),
+}
+
+/// The camera input gathered this frame from every bound input source. Added automatically with
+/// the ThirdPersonCamera component and refreshed every frame in `PreUpdate`.
+#[derive(Component, Default, Debug)]
+pub struct CameraInput {
+    /// Orbit movement in mouse counts, accumulated over the frame.
+    pub orbit_delta: Vec2,
+    /// Orbit movement from sticks & buttons, in the range -1..1.
+    pub orbit_rate: Vec2,
+    /// Zoom movement in scroll wheel steps, accumulated over the frame.
+    pub zoom_delta: f32,
+    /// Zoom movement from gamepad axes & buttons, in the range -1..1.
+    pub zoom_rate: f32,
+    /// Whether the zoom step action was just pressed.
+    pub zoom_step: bool,
+    /// Whether the aim action is active.
+    pub aim: bool,
+    /// Whether the orbit button is active.
+    pub orbit_button: bool,
+    /// Whether the shoulder swap action was just pressed.
+    pub shoulder_swap: bool,
+    /// Whether the cursor lock should be flipped this frame.
+    pub cursor_toggle: bool,
+    /// Whether the recenter action was just pressed.
+    pub recenter: bool,
+    /// Whether the lock-on action was just pressed.
+    pub lock_on: bool,
+    /// Whether the lock-on next action was just pressed.
+    pub lock_on_next: bool,
+    /// Whether the lock-on previous action was just pressed.
+    pub lock_on_previous: bool,
+    /// Whether the free fly action was just pressed.
+    pub free_fly: bool,
+    /// Free fly movement in the range -1..1, with x to the right, y up & z forward.
+    /// In photo mode, x & z pan the camera right & up.
+    pub fly_rate: Vec3,
+    /// Whether the photo mode action was just pressed.
+    pub photo_mode: bool,
+    /// Photo mode roll, in the range -1..1.
+    pub roll_rate: f32,
+    /// Photo mode field of view change, in the range -1..1.
+    pub fov_rate: f32,
+    /// Photo mode focus distance change, in the range -1..1.
+    pub focus_rate: f32,
+    /// Photo mode aperture change, in the range -1..1.
+    pub aperture_rate: f32,
+    activation: ActivationStates,
+}
+
+// sticks must be pushed past this value before the camera orbits
+const STICK_DEADZONE: f32 = 0.5;
+
+/// Returns the gamepads that control a camera. Every connected gamepad is returned when the
+/// camera isn't bound to a specific one.
+pub(crate) fn camera_gamepads<'a>(
+    settings: &'a CustomGamepadSettings,
+    gamepad_q: &'a Query<(Entity, &Gamepad)>,
+) -> impl Iterator<Item = &'a Gamepad> {
+    gamepad_q
+        .iter()
+        .filter(|(entity, _)| settings.gamepad.is_none_or(|gamepad| gamepad == *entity))
+        .map(|(_, gamepad)| gamepad)
+}
+
+// the input devices that control a single camera
+struct InputSources<'a> {
+    keys: Option<&'a ButtonInput<KeyCode>>,
+    mouse: Option<&'a ButtonInput<MouseButton>>,
+    gamepads: Vec<&'a Gamepad>,
+}
+
+impl InputSources<'_> {
+    fn pressed(&self, binding: &InputBinding) -> bool {
+        self.keys.is_some_and(|keys| binding.pressed_key(keys))
+            || self.mouse.is_some_and(|mouse| binding.pressed_mouse(mouse))
+            || self
+                .gamepads
+                .iter()
+                .any(|gamepad| binding.pressed_gamepad(gamepad.digital()))
+    }
+
+    fn just_pressed(&self, binding: &InputBinding) -> bool {
+        self.keys.is_some_and(|keys| binding.just_pressed_key(keys))
+            || self
+                .mouse
+                .is_some_and(|mouse| binding.just_pressed_mouse(mouse))
+            || self
+                .gamepads
+                .iter()
+                .any(|gamepad| binding.just_pressed_gamepad(gamepad.digital()))
+    }
+
+    fn button_axis(&self, negative: &InputBinding, positive: &InputBinding) -> f32 {
+        self.pressed(positive) as i8 as f32 - self.pressed(negative) as i8 as f32
+    }
+
+    fn gamepad_axis(&self, axis: GamepadAxis) -> f32 {
+        self.gamepads
+            .iter()
+            .filter_map(|gamepad| gamepad.get(axis))
+            .sum()
+    }
+
+    // the sum of every gamepad axis & button pair bound to an action, the scroll wheel is ignored
+    fn axis(&self, bindings: &[AxisInput]) -> f32 {
+        bindings
+            .iter()
+            .map(|binding| match binding {
+                AxisInput::MouseWheel => 0.0,
+                AxisInput::GamepadAxis(axis) => self.gamepad_axis(*axis),
+                AxisInput::Buttons(negative, positive) => self.button_axis(negative, positive),
+            })
+            .sum::<f32>()
+            .clamp(-1.0, 1.0)
+    }
+
+    fn stick(&self, x: GamepadAxis, y: GamepadAxis) -> Vec2 {
+        let stick = Vec2::new(self.gamepad_axis(x), self.gamepad_axis(y));
+        if stick.x.abs() > STICK_DEADZONE || stick.y.abs() > STICK_DEADZONE {
+            stick
+        } else {
+            Vec2::ZERO
+        }
+    }
+}
+
+pub(crate) fn read_camera_input(
+    real_time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut mouse_evr: MessageReader<MouseMotion>,
+    mut scroll_evr: MessageReader<MouseWheel>,
+    gamepad_q: Query<(Entity, &Gamepad)>,
+    mut cam_q: Query<(
+        &ThirdPersonCamera,
+        &ThirdPersonCameraState,
+        &mut CameraInput,
+    )>,
+) {
+    let mut mouse_delta = Vec2::ZERO;
+    for ev in mouse_evr.read() {
+        mouse_delta += ev.delta;
+    }
+
+    let mut scroll = 0.0;
+    for ev in scroll_evr.read() {
+        scroll += ev.y;
+    }
+
+    for (cam, state, mut input) in cam_q.iter_mut() {
+        let sources = InputSources {
+            keys: cam.keyboard_mouse_enabled.then_some(&*keys),
+            mouse: cam.keyboard_mouse_enabled.then_some(&*mouse),
+            gamepads: camera_gamepads(&cam.gamepad_settings, &gamepad_q).collect(),
+        };
+        let actions = &cam.actions;
+
+        // mouse movement is only camera input while the cursor is locked
+        let mouse_active = cam.keyboard_mouse_enabled && cam.cursor_lock_active;
+
+        let mut orbit_delta = Vec2::ZERO;
+        let mut orbit_rate = Vec2::ZERO;
+        for binding in actions.orbit.iter() {
+            match binding {
+                DualAxisInput::MouseMotion if mouse_active => orbit_delta += mouse_delta,
+                DualAxisInput::MouseMotion => {}
+                DualAxisInput::GamepadStick(x, y) => orbit_rate += sources.stick(*x, *y),
+                DualAxisInput::Buttons(left, right, down, up) => {
+                    orbit_rate += Vec2::new(
+                        sources.button_axis(left, right),
+                        sources.button_axis(down, up),
+                    );
+                }
+            }
+        }
+
+        let mut zoom_delta = 0.0;
+        let mut zoom_rate = 0.0;
+        for binding in actions.zoom.iter() {
+            match binding {
+                AxisInput::MouseWheel if mouse_active => zoom_delta += scroll,
+                AxisInput::MouseWheel => {}
+                AxisInput::GamepadAxis(axis) => zoom_rate += sources.gamepad_axis(*axis),
+                AxisInput::Buttons(negative, positive) => {
+                    zoom_rate += sources.button_axis(negative, positive);
+                }
+            }
+        }
+
+        let mut fly = Vec2::ZERO;
+        for binding in actions.fly.iter() {
+            match binding {
+                DualAxisInput::MouseMotion => {}
+                DualAxisInput::GamepadStick(x, y) => fly += sources.stick(*x, *y),
+                DualAxisInput::Buttons(left, right, back, forward) => {
+                    fly += Vec2::new(
+                        sources.button_axis(left, right),
+                        sources.button_axis(back, forward),
+                    );
+                }
+            }
+        }
+
+        let fly_vertical = sources.axis(&actions.fly_vertical);
+        let fly_rate = Vec3::new(fly.x, fly_vertical, fly.y).clamp(Vec3::NEG_ONE, Vec3::ONE);
+
+        // real time, so pausing or slowing down the game doesn't stretch the double tap window
+        let now = real_time.elapsed_secs();
+        let window = actions.double_tap_window;
+        let mut activation = std::mem::take(&mut input.activation);
+        activation.aim.update(
+            actions.aim_activation,
+            sources.pressed(&actions.aim),
+            sources.just_pressed(&actions.aim),
+            now,
+            window,
+        );
+        activation.orbit_button.update(
+            actions.orbit_button_activation,
+            sources.pressed(&actions.orbit_button),
+            sources.just_pressed(&actions.orbit_button),
+            now,
+            window,
+        );
+        let cursor_toggle = activation.cursor_toggle.update(
+            actions.cursor_toggle_activation,
+            sources.pressed(&actions.cursor_toggle),
+            sources.just_pressed(&actions.cursor_toggle),
+            now,
+            window,
+        );
+
+        // a free flying camera only looks around & moves, the other actions need a target.
+        // photo mode keeps orbiting & zooming, and pans with the fly action
+        let photo = state.is_in_photo_mode();
+        let orbiting = !state.free_fly && !photo;
+        *input = CameraInput {
+            orbit_delta,
+            orbit_rate,
+            zoom_delta: if orbiting || photo { zoom_delta } else { 0.0 },
+            zoom_rate: if orbiting || photo { zoom_rate } else { 0.0 },
+            zoom_step: orbiting && sources.just_pressed(&actions.zoom_step),
+            aim: orbiting && activation.aim.active,
+            orbit_button: activation.orbit_button.active,
+            shoulder_swap: orbiting && sources.just_pressed(&actions.shoulder_swap),
+            cursor_toggle,
+            recenter: orbiting && sources.just_pressed(&actions.recenter),
+            lock_on: orbiting && sources.just_pressed(&actions.lock_on),
+            lock_on_next: orbiting && sources.just_pressed(&actions.lock_on_next),
+            lock_on_previous: orbiting && sources.just_pressed(&actions.lock_on_previous),
+            free_fly: cam.free_fly_enabled && !photo && sources.just_pressed(&actions.free_fly),
+            fly_rate: match (state.free_fly, photo) {
+                (true, _) => fly_rate,
+                (_, true) => Vec3::new(fly.x, 0.0, fly.y).clamp(Vec3::NEG_ONE, Vec3::ONE),
+                _ => Vec3::ZERO,
+            },
+            photo_mode: cam.photo_mode.enabled && sources.just_pressed(&actions.photo_mode),
+            roll_rate: if photo {
+                sources.axis(&actions.roll)
+            } else {
+                0.0
+            },
+            fov_rate: if photo {
+                sources.axis(&actions.fov)
+            } else {
+                0.0
+            },
+            focus_rate: if photo {
+                sources.axis(&actions.focus)
+            } else {
+                0.0
+            },
+            aperture_rate: if photo {
+                sources.axis(&actions.aperture)
+            } else {
+                0.0
+            },
+            activation,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: f32 = 0.3;
+
+    // presses & releases the action at the given times, returning whether it ends up active
+    fn tap(state: &mut ActionState, mode: ActivationMode, times: &[f32]) -> bool {
+        for &now in times {
+            state.update(mode, true, true, now, WINDOW);
+            state.update(mode, false, false, now + 0.05, WINDOW);
+        }
+        state.active
+    }
+
+    #[test]
+    fn hold_is_active_while_pressed() {
+        let mut state = ActionState::default();
+        assert!(state.update(ActivationMode::Hold, true, true, 0.0, WINDOW));
+        assert!(!state.update(ActivationMode::Hold, true, false, 0.1, WINDOW));
+        assert!(state.active);
+        assert!(state.update(ActivationMode::Hold, false, false, 0.2, WINDOW));
+        assert!(!state.active);
+    }
+
+    #[test]
+    fn toggle_switches_on_each_press() {
+        let mut state = ActionState::default();
+        assert!(tap(&mut state, ActivationMode::Toggle, &[0.0]));
+        assert!(!tap(&mut state, ActivationMode::Toggle, &[1.0]));
+        assert!(!tap(&mut state, ActivationMode::Toggle, &[2.0, 3.0]));
+    }
+
+    #[test]
+    fn double_tap_needs_two_presses_within_the_window() {
+        let mut state = ActionState::default();
+        assert!(!tap(&mut state, ActivationMode::DoubleTap, &[0.0]));
+        assert!(tap(&mut state, ActivationMode::DoubleTap, &[0.2]));
+        // a third press starts a new double tap rather than switching off
+        assert!(tap(&mut state, ActivationMode::DoubleTap, &[0.4]));
+        assert!(!tap(&mut state, ActivationMode::DoubleTap, &[0.5]));
+    }
+
+    #[test]
+    fn double_tap_ignores_presses_too_far_apart() {
+        let mut state = ActionState::default();
+        assert!(!tap(
+            &mut state,
+            ActivationMode::DoubleTap,
+            &[0.0, 0.5, 1.0]
+        ));
+        assert!(tap(&mut state, ActivationMode::DoubleTap, &[1.2]));
+    }
+}
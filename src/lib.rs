@@ -1,12 +1,63 @@
+use std::f32::consts::FRAC_PI_2;
+
+use aim::AimPlugin;
 use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
     prelude::*,
     window::{CursorGrabMode, CursorOptions, PrimaryWindow},
 };
-use gamepad::GamePadPlugin;
-use mouse::MousePlugin;
-
-mod gamepad;
-mod mouse;
+use fade::FadePlugin;
+use free_fly::FreeFlyPlugin;
+use input::InputPlugin;
+use lock_on::LockOnPlugin;
+use orbit::OrbitPlugin;
+use photo::{PhotoPlugin, PhotoSession};
+#[cfg(feature = "avian3d")]
+use physics::PhysicsPlugin;
+use profile::ProfilePlugin;
+use shake::ShakePlugin;
+use time::CameraTime;
+
+mod aim;
+mod collision;
+mod damping;
+mod fade;
+mod first_person;
+mod free_fly;
+mod input;
+mod lock_on;
+#[cfg(feature = "mesh_picking")]
+mod mesh_occlusion;
+mod occlusion;
+mod orbit;
+mod photo;
+#[cfg(feature = "avian3d")]
+mod physics;
+mod pivot;
+mod profile;
+mod recenter;
+mod shake;
+mod time;
+
+pub use aim::{AimFacing, AimMode, AimRotation};
+pub use bevy_unified_input::{InputBinding, InputKind};
+pub use collision::CameraCollision;
+use damping::wrap_angle;
+pub use damping::{Damping, FollowDamping};
+pub use fade::{CameraFade, CameraFadeable, NearTarget};
+pub use first_person::FirstPerson;
+pub use input::{ActivationMode, AxisInput, CameraActionMap, CameraInput, DualAxisInput};
+pub use lock_on::{LockOn, LockOnTarget};
+#[cfg(feature = "mesh_picking")]
+pub use mesh_occlusion::MeshOcclusion;
+pub use occlusion::{CameraOcclusionPlugin, CameraOcclusionProvider, OcclusionCast};
+pub use photo::PhotoMode;
+#[cfg(feature = "avian3d")]
+pub use physics::{AvianOcclusion, AvianOcclusionFilter, PhysicsFollow};
+pub use pivot::{CameraTargetVelocity, LookAhead, TargetOffset};
+pub use profile::{CameraProfile, CameraProfileStack, ProfileTransition};
+pub use recenter::{Recenter, YawLimits};
+pub use shake::{CameraShake, ShakeCamera};
 
 /// # Examples
 ///
@@ -20,23 +71,58 @@ pub struct ThirdPersonCameraPlugin;
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CameraSyncSet;
 
+// the steps of CameraSyncSet, in order
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum CameraSyncSystems {
+    // places the camera relative to its target
+    Follow,
+    // pulls the camera in front of obstructing geometry
+    Collision,
+    // fades occluders & the target, from the camera transform before effects move it
+    Fade,
+    // visual effects that don't affect the orbit, such as shake
+    Effects,
+}
+
 impl Plugin for ThirdPersonCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((MousePlugin, GamePadPlugin))
-            .add_systems(
-                Update,
-                (
-                    aim.run_if(aim_condition),
-                    toggle_x_offset.run_if(toggle_x_offset_condition),
-                    toggle_cursor.run_if(toggle_cursor_condition),
-                ),
+        app.add_plugins((
+            InputPlugin,
+            OrbitPlugin,
+            LockOnPlugin,
+            ShakePlugin,
+            ProfilePlugin,
+            FadePlugin,
+            FreeFlyPlugin,
+            PhotoPlugin,
+            AimPlugin,
+        ))
+        .add_systems(
+            Update,
+            (
+                toggle_x_offset.run_if(toggle_x_offset_condition),
+                toggle_cursor.run_if(toggle_cursor_condition),
+            ),
+        )
+        .configure_sets(
+            PostUpdate,
+            (
+                CameraSyncSystems::Follow,
+                CameraSyncSystems::Collision,
+                CameraSyncSystems::Fade,
+                CameraSyncSystems::Effects,
             )
-            .add_systems(
-                PostUpdate,
-                sync_player_camera
-                    .before(TransformSystems::Propagate)
-                    .in_set(CameraSyncSet),
-            );
+                .chain()
+                .in_set(CameraSyncSet)
+                .before(TransformSystems::Propagate),
+        )
+        .add_systems(
+            PostUpdate,
+            sync_player_camera.in_set(CameraSyncSystems::Follow),
+        );
+
+        #[cfg(feature = "avian3d")]
+        app.add_plugins(PhysicsPlugin);
     }
 }
 
@@ -53,13 +139,14 @@ impl Plugin for ThirdPersonCameraPlugin {
 /// }
 /// ```
 #[derive(Component)]
+#[require(ThirdPersonCameraState, CameraInput)]
 pub struct ThirdPersonCamera {
+    /// The key, mouse & gamepad bindings of every camera action.
+    /// Default is CameraActionMap::default()
+    pub actions: CameraActionMap,
     /// Flag to indicate if the aim functionality is turned on.
     /// Default is false
     pub aim_enabled: bool,
-    /// The mouse aim button binding.
-    /// Default is MouseButton::Right
-    pub aim_button: MouseButton,
     /// The speed at which aiming occurs.
     /// Default is 3.0
     pub aim_speed: f32,
@@ -68,6 +155,35 @@ pub struct ThirdPersonCamera {
     /// The smaller the value, the greater the zoom distance. 0.1 would essentially look like 'first person'.
     /// Default is 0.7
     pub aim_zoom: f32,
+    /// How the camera zooms in while aiming: by shrinking the zoom radius, by narrowing the field of view, or both.
+    /// Default is AimMode::Radius
+    pub aim_mode: AimMode,
+    /// The vertical field of view while aiming, in radians. Only used by AimMode::Fov & AimMode::RadiusAndFov.
+    /// Default is 40 degrees
+    pub aim_fov: f32,
+    /// The time in seconds it takes to blend into and out of the aim field of view & aim offset.
+    /// Default is 0.2
+    pub aim_duration: f32,
+    /// The easing of the aim field of view & aim offset blend.
+    /// Default is EaseFunction::CubicOut
+    pub aim_ease: EaseFunction,
+    /// A dedicated over-the-shoulder offset used while aiming, instead of the regular offset.
+    /// The x value follows the shoulder swap.
+    /// Example: aim_offset: Some(Vec2::new(0.6, 0.3))
+    /// Default is None
+    pub aim_offset: Option<Vec2>,
+    /// How the target is turned to face the aim direction while aiming.
+    /// Default is AimRotation::Yaw
+    pub aim_rotation: AimRotation,
+    /// Flag to indicate if the free fly action can detach the camera from its target, e.g. for level debugging & replays.
+    /// Default is false
+    pub free_fly_enabled: bool,
+    /// The speed of a free flying camera, in units per second.
+    /// Default is 8.0
+    pub free_fly_speed: f32,
+    /// Photo mode settings.
+    /// Default is PhotoMode::default() (photo mode disabled)
+    pub photo_mode: PhotoMode,
     /// Flag to indicate if the cursor lock toggle functionality is turned on.
     /// When enabled and the cursor lock is NOT active, the mouse can freely move about the window without the camera's transform changing.
     /// Example usage: Browsing a character inventory without moving the camera.
@@ -76,20 +192,33 @@ pub struct ThirdPersonCamera {
     /// Flag to indicate if the cursor is in a locked state or not.
     /// Default is true
     pub cursor_lock_active: bool,
-    /// The cursor lock toggle key binding.
-    /// Default is KeyCode::Space
-    pub cursor_lock_key: KeyCode,
+    /// Smoothing applied to the camera's position, rotation and zoom as it follows the target.
+    /// Default is FollowDamping::default() (zoom smoothing only)
+    pub damping: FollowDamping,
     /// Custom gamepad settings.
     pub gamepad_settings: CustomGamepadSettings,
-    /// Mouse x/y sensitivity
-    /// Default is Vec2::new(1.0, 1.0)
+    /// Flag to indicate if this camera is controlled by the keyboard & mouse.
+    /// Disable this for cameras that should only respond to their own gamepad, e.g. the second player in split-screen.
+    /// Default is true
+    pub keyboard_mouse_enabled: bool,
+    /// The lowest the camera can look, in radians. Negative values look down at the target from above.
+    /// Example: min_pitch: (-60.0_f32).to_radians()
+    /// Default is -89 degrees
+    pub min_pitch: f32,
+    /// The highest the camera can look, in radians. Positive values look up at the target from below.
+    /// Example: max_pitch: 30.0_f32.to_radians()
+    /// Default is 89 degrees
+    pub max_pitch: f32,
+    /// Mouse x/y sensitivity, in degrees the camera rotates per mouse count.
+    /// This is independent of the window resolution & frame rate.
+    /// Default is Vec2::new(0.15, 0.15)
     pub sensitivity: Vec2,
-    /// Flag to indicate if the orbiting functionality is controlled when a mouse button is being pressed.
+    /// Lock-on settings.
+    /// Default is LockOn::default() (lock-on disabled)
+    pub lock_on: LockOn,
+    /// Flag to indicate if the orbiting functionality is controlled when the orbit button is being pressed.
     /// Default is false
     pub mouse_orbit_button_enabled: bool,
-    /// The mouse button binding to control when the orbiting occurs.
-    /// Default is MouseButton:Middle
-    pub mouse_orbit_button: MouseButton,
     /// Flag to indicate whether there is a camera offset applied or not.
     /// Default is false
     pub offset_enabled: bool,
@@ -102,12 +231,12 @@ pub struct ThirdPersonCamera {
     /// Example: If the x offset is set to 5.0, then the x offset will be inverted to -5.0 if this is set to true.
     /// Default is false
     pub offset_toggle_enabled: bool,
-    /// The key binding of the offset toggle
-    /// Default is KeyCode::KeyE
-    pub offset_toggle_key: KeyCode,
     /// The speed at which the x offset will transition.
     /// Default is 5.0
     pub offset_toggle_speed: f32,
+    /// Settings for swinging the camera back behind the target, either automatically or with the recenter action.
+    /// Default is Recenter::default() (auto recenter disabled)
+    pub recenter: Recenter,
     /// Flag to indicate whether a camera zoom is applied or not.
     /// Default is true
     pub zoom_enabled: bool,
@@ -120,30 +249,88 @@ pub struct ThirdPersonCamera {
     /// The speed at which the scroll wheel zooms in or out.
     /// Default is 1.0
     pub zoom_sensitivity: f32,
+    /// The speed at which gamepad axes & buttons zoom in or out, as a fraction of the radius per second.
+    /// Default is 0.6
+    pub zoom_speed: f32,
+    /// Restricts the horizontal orbit to an arc relative to the direction the target is facing.
+    /// Example: yaw_limits: Some(YawLimits::symmetric(70.0_f32.to_radians()))
+    /// Default is None
+    pub yaw_limits: Option<YawLimits>,
+    /// The point the camera orbits around, relative to the target. Raise it to chest or head height
+    /// when the target's origin is at its feet.
+    /// Example: pivot_offset: TargetOffset::World(Vec3::new(0.0, 1.5, 0.0))
+    /// Default is TargetOffset::World(Vec3::ZERO)
+    pub pivot_offset: TargetOffset,
+    /// A point, relative to the target, the camera looks at instead of the pivot. The camera still
+    /// orbits around the pivot.
+    /// Default is None
+    pub look_at: Option<TargetOffset>,
+    /// Moves the pivot ahead of the target in the direction it is moving, so more of what's ahead is in view.
+    /// Default is LookAhead::default() (disabled)
+    pub look_ahead: LookAhead,
+    /// Hides or fades the target when the camera comes too close to it, e.g. when aiming or when
+    /// collision pulls the camera in, so the camera doesn't end up inside the target's model.
+    /// Default is NearTarget::Off
+    pub near_target: NearTarget,
+    /// Switches to a first person view at the target's eye point when zooming in past `zoom.min`.
+    /// Zooming back out returns to third person. None disables first person.
+    /// Example: first_person: Some(FirstPerson::default())
+    /// Default is None
+    pub first_person: Option<FirstPerson>,
+    /// How a target driven by avian3d is followed.
+    /// Default is PhysicsFollow::Interpolate
+    #[cfg(feature = "avian3d")]
+    pub physics_follow: PhysicsFollow,
+    /// The entity this camera follows.
+    /// If None, the camera follows the only ThirdPersonCameraTarget in the world. Set this when there are multiple cameras and targets (split-screen).
+    /// Default is None
+    pub target: Option<Entity>,
 }
 
 impl Default for ThirdPersonCamera {
     fn default() -> Self {
         ThirdPersonCamera {
+            actions: CameraActionMap::default(),
             aim_enabled: false,
-            aim_button: MouseButton::Right,
             aim_speed: 3.0,
             aim_zoom: 0.7,
-            cursor_lock_key: KeyCode::Space,
+            aim_mode: AimMode::Radius,
+            aim_fov: 40.0_f32.to_radians(),
+            aim_duration: 0.2,
+            aim_ease: EaseFunction::CubicOut,
+            aim_offset: None,
+            aim_rotation: AimRotation::Yaw,
+            free_fly_enabled: false,
+            free_fly_speed: 8.0,
+            photo_mode: PhotoMode::default(),
             cursor_lock_toggle_enabled: true,
+            damping: FollowDamping::default(),
             gamepad_settings: CustomGamepadSettings::default(),
+            keyboard_mouse_enabled: true,
+            lock_on: LockOn::default(),
+            min_pitch: -89.0_f32.to_radians(),
+            max_pitch: 89.0_f32.to_radians(),
             cursor_lock_active: true,
-            sensitivity: Vec2::new(1.0, 1.0),
+            sensitivity: Vec2::new(0.15, 0.15),
             mouse_orbit_button_enabled: false,
-            mouse_orbit_button: MouseButton::Middle,
             offset_enabled: false,
             offset: Offset::new(0.5, 0.4),
             offset_toggle_enabled: false,
             offset_toggle_speed: 5.0,
-            offset_toggle_key: KeyCode::KeyE,
+            recenter: Recenter::default(),
             zoom_enabled: true,
             zoom: Zoom::new(1.5, 3.0),
             zoom_sensitivity: 1.0,
+            zoom_speed: 0.6,
+            yaw_limits: None,
+            pivot_offset: TargetOffset::default(),
+            look_at: None,
+            look_ahead: LookAhead::default(),
+            near_target: NearTarget::Off,
+            first_person: None,
+            #[cfg(feature = "avian3d")]
+            physics_follow: PhysicsFollow::Interpolate,
+            target: None,
         }
     }
 }
@@ -153,12 +340,237 @@ impl ThirdPersonCamera {
         self.gamepad_settings = gamepad_settings;
         self
     }
+
+    pub fn with_target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Returns the entity this camera follows. Falls back to the only ThirdPersonCameraTarget
+    /// when no explicit target is set.
+    pub(crate) fn target_entity(
+        &self,
+        target_q: &Query<Entity, With<ThirdPersonCameraTarget>>,
+    ) -> Option<Entity> {
+        self.target.or_else(|| target_q.single().ok())
+    }
+}
+
+/// The runtime state of a ThirdPersonCamera. Added automatically with the ThirdPersonCamera component.
+/// The orbit rotation lives here instead of the camera's Transform, so that smoothing never changes where the player is looking.
+/// The initial orbit rotation is taken from the camera's Transform when it is spawned.
+#[derive(Component, Clone)]
+#[component(on_add = init_camera_state)]
+pub struct ThirdPersonCameraState {
+    yaw: f32,
+    pitch: f32,
+    current_yaw: f32,
+    current_pitch: f32,
+    pivot: Vec3,
+    radius: f32,
+    yaw_velocity: f32,
+    pitch_velocity: f32,
+    pivot_velocity: Vec3,
+    radius_velocity: f32,
+    look_ahead: Vec3,
+    look_ahead_velocity: Vec3,
+    last_pivot_target: Option<Vec3>,
+    idle_secs: f32,
+    recentering: bool,
+    last_target_translation: Option<Vec3>,
+    locked_target: Option<Entity>,
+    line_of_sight_lost_secs: f32,
+    aim_progress: f32,
+    aim_blend: f32,
+    base_fov: Option<f32>,
+    // the target hidden by NearTarget::Hide & its visibility before it was hidden
+    near_hidden: Option<(Entity, Visibility)>,
+    // the target faded by NearTarget::Fade
+    near_faded: Option<Entity>,
+    first_person: bool,
+    first_person_progress: f32,
+    free_fly: bool,
+    // the world position of a free flying camera, captured on the first frame of free fly
+    fly_position: Option<Vec3>,
+    photo_requested: bool,
+    photo: Option<Box<PhotoSession>>,
+    snap: bool,
+}
+
+impl Default for ThirdPersonCameraState {
+    fn default() -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 0.0,
+            current_yaw: 0.0,
+            current_pitch: 0.0,
+            pivot: Vec3::ZERO,
+            radius: 0.0,
+            yaw_velocity: 0.0,
+            pitch_velocity: 0.0,
+            pivot_velocity: Vec3::ZERO,
+            radius_velocity: 0.0,
+            look_ahead: Vec3::ZERO,
+            look_ahead_velocity: Vec3::ZERO,
+            last_pivot_target: None,
+            idle_secs: 0.0,
+            recentering: false,
+            last_target_translation: None,
+            locked_target: None,
+            line_of_sight_lost_secs: 0.0,
+            aim_progress: 0.0,
+            aim_blend: 0.0,
+            base_fov: None,
+            near_hidden: None,
+            near_faded: None,
+            first_person: false,
+            first_person_progress: 0.0,
+            free_fly: false,
+            fly_position: None,
+            photo_requested: false,
+            photo: None,
+            snap: true,
+        }
+    }
+}
+
+impl ThirdPersonCameraState {
+    // keeps the camera from flipping over when looking straight up or down
+    const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+    /// The desired orbit rotation around the y axis, in radians.
+    pub fn yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    /// The desired orbit rotation around the x axis, in radians.
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    /// The desired orbit rotation, before smoothing is applied.
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0)
+    }
+
+    /// Sets the desired orbit rotation, in radians.
+    pub fn set_rotation(&mut self, yaw: f32, pitch: f32) {
+        self.yaw = yaw;
+        self.pitch = pitch.clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+    }
+
+    /// The entity the camera is currently locked on to, if any.
+    pub fn locked_target(&self) -> Option<Entity> {
+        self.locked_target
+    }
+
+    /// The point the camera currently orbits around, including smoothing.
+    pub fn pivot(&self) -> Vec3 {
+        self.pivot
+    }
+
+    /// Whether the camera is in first person, or blending into it. Requires `first_person` to be set.
+    pub fn is_first_person(&self) -> bool {
+        self.first_person
+    }
+
+    /// Switches into or out of first person, blending over `FirstPerson::duration`.
+    pub fn set_first_person(&mut self, first_person: bool) {
+        self.first_person = first_person;
+    }
+
+    /// Whether the camera is detached from its target and flying freely.
+    pub fn is_free_flying(&self) -> bool {
+        self.free_fly
+    }
+
+    /// Detaches the camera to fly freely from where it is, or snaps it back to orbit the target.
+    pub fn set_free_fly(&mut self, free_fly: bool) {
+        if self.free_fly && !free_fly {
+            self.fly_position = None;
+            self.snap = true;
+        }
+        self.free_fly = free_fly;
+    }
+
+    /// Whether the camera is in photo mode.
+    pub fn is_in_photo_mode(&self) -> bool {
+        self.photo.is_some()
+    }
+
+    /// Enters or leaves photo mode on the next frame. Leaving restores the camera as it was.
+    pub fn set_photo_mode(&mut self, photo_mode: bool) {
+        self.photo_requested = photo_mode;
+    }
+
+    /// Skips smoothing for the next frame. Useful after teleporting the target.
+    pub fn snap(&mut self) {
+        self.snap = true;
+    }
+
+    /// Rotates the camera, keeping the pitch within the camera's pitch limits. The yaw limits
+    /// are applied around `target_yaw`, the direction the target is facing.
+    pub(crate) fn orbit(
+        &mut self,
+        cam: &ThirdPersonCamera,
+        delta_yaw: f32,
+        delta_pitch: f32,
+        target_yaw: Option<f32>,
+    ) {
+        // first person, free fly & photo mode look around freely
+        let unlimited = self.free_fly
+            || self.photo.is_some()
+            || (self.first_person && cam.first_person.is_some());
+
+        let yaw = match (&cam.yaw_limits, target_yaw) {
+            (Some(limits), Some(target_yaw)) if !unlimited => {
+                let relative_yaw = wrap_angle(self.yaw - target_yaw);
+                target_yaw + limits.apply(relative_yaw, delta_yaw)
+            }
+            _ => self.yaw + delta_yaw,
+        };
+
+        let mut pitch = self.pitch + delta_pitch;
+        if !unlimited {
+            pitch = pitch.clamp(cam.min_pitch, cam.max_pitch.max(cam.min_pitch));
+        }
+        self.set_rotation(yaw, pitch);
+    }
+}
+
+/// The yaw that places the camera directly behind a target with the given transform.
+pub(crate) fn target_yaw(target: &GlobalTransform) -> f32 {
+    let forward = target.forward();
+    f32::atan2(-forward.x, -forward.z)
+}
+
+fn init_camera_state(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let Some(rotation) = world.get::<Transform>(entity).map(|t| t.rotation) else {
+        return;
+    };
+    if let Some(mut state) = world.get_mut::<ThirdPersonCameraState>(entity) {
+        let (yaw, pitch, _) = rotation.to_euler(EulerRot::YXZ);
+        state.set_rotation(yaw, pitch);
+    }
 }
 
 /// Sets the zoom bounds (min & max)
+///
+/// Zoom input changes the target radius. The camera eases towards it using `damping.zoom`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_third_person_camera::Zoom;
+/// // near, mid & far presets, cycled with the zoom step action
+/// let zoom = Zoom::new(1.5, 8.0).with_steps([2.0, 4.5, 8.0]);
+/// ```
 pub struct Zoom {
     pub min: f32,
     pub max: f32,
+    /// Discrete radii cycled through from near to far with the zoom step action.
+    /// Empty disables the zoom step action.
+    pub steps: Vec<f32>,
     radius: f32,
     radius_copy: Option<f32>,
 }
@@ -168,10 +580,51 @@ impl Zoom {
         Self {
             min,
             max,
+            steps: Vec::new(),
             radius: (min + max) / 2.0,
             radius_copy: None,
         }
     }
+
+    pub fn with_steps(mut self, steps: impl Into<Vec<f32>>) -> Self {
+        self.steps = steps.into();
+        self
+    }
+
+    /// The radius the camera is zooming towards.
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    // the next step further away than the current radius, wrapping back to the nearest step
+    fn next_step(&self) -> Option<f32> {
+        let nearest = self.steps.iter().copied().min_by(f32::total_cmp)?;
+        let next = self
+            .steps
+            .iter()
+            .copied()
+            .filter(|step| *step > self.radius + 0.01)
+            .min_by(f32::total_cmp);
+        Some(next.unwrap_or(nearest).clamp(self.min, self.max))
+    }
+}
+
+/// The world transform of an entity, composed from the transforms of its ancestors. Unlike the
+/// GlobalTransform component, it is already up to date before transform propagation.
+pub(crate) fn world_transform(
+    entity: Entity,
+    transform_q: &Query<(&Transform, Option<&ChildOf>), Without<ThirdPersonCamera>>,
+) -> Option<GlobalTransform> {
+    let (transform, mut child_of) = transform_q.get(entity).ok()?;
+    let mut global = GlobalTransform::from(*transform);
+    while let Some(parent) = child_of {
+        let Ok((parent_transform, parent_child_of)) = transform_q.get(parent.parent()) else {
+            break;
+        };
+        global = GlobalTransform::from(*parent_transform) * global;
+        child_of = parent_child_of;
+    }
+    Some(global)
 }
 
 /// Offset the camera behind the player. For example, an offset value of (0.5, 0.25) will
@@ -209,35 +662,21 @@ impl Offset {
 /// ```
 #[derive(Component)]
 pub struct CustomGamepadSettings {
-    /// The aim button binding.
-    /// Default is GamepadButton::new(gamepad, GamepadButtonType::LeftTrigger2)
-    pub aim_button: GamepadButton,
-    /// The button binding to control when the orbiting occurs.
-    /// Default is GamepadButton::new(gamepad, GamepadButtonType::LeftTrigger)
-    pub mouse_orbit_button: GamepadButton,
-    /// The button binding of the offset toggle
-    /// Default is GamepadButton::new(gamepad, GamepadButtonType::DPadRight)
-    pub offset_toggle_button: GamepadButton,
-    /// Gamepad x/y sensitivity
-    /// Default is Vec2::new(7.0, 4.0)
+    /// The gamepad entity that controls this camera.
+    /// If None, every connected gamepad controls the camera. Set this when there are multiple cameras (split-screen).
+    /// Default is None
+    pub gamepad: Option<Entity>,
+    /// Gamepad x/y sensitivity, in degrees the camera rotates per second at full stick deflection.
+    /// This is independent of the window resolution & frame rate.
+    /// Default is Vec2::new(120.0, 60.0)
     pub sensitivity: Vec2,
-    /// The camera zoom in button binding (equivalent to the scroll wheel zoom)
-    /// GamepadButton::new(gamepad, GamepadButtonType::DPadUp)
-    pub zoom_in_button: GamepadButton,
-    /// The camera zoom out button binding (equivalent to the scroll wheel zoom)
-    /// GamepadButton::new(gamepad, GamepadButtonType::DPadDown)
-    pub zoom_out_button: GamepadButton,
 }
 
 impl Default for CustomGamepadSettings {
     fn default() -> Self {
         Self {
-            aim_button: GamepadButton::LeftTrigger2,
-            mouse_orbit_button: GamepadButton::LeftTrigger,
-            offset_toggle_button: GamepadButton::DPadRight,
-            sensitivity: Vec2::new(7.0, 4.0),
-            zoom_in_button: GamepadButton::DPadUp,
-            zoom_out_button: GamepadButton::DPadDown,
+            gamepad: None,
+            sensitivity: Vec2::new(120.0, 60.0),
         }
     }
 }
@@ -260,163 +699,224 @@ impl Default for CustomGamepadSettings {
 pub struct ThirdPersonCameraTarget;
 
 fn sync_player_camera(
-    player_q: Query<&Transform, With<ThirdPersonCameraTarget>>,
-    mut cam_q: Query<(&mut ThirdPersonCamera, &mut Transform), Without<ThirdPersonCameraTarget>>,
+    time: CameraTime,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    transform_q: Query<(&Transform, Option<&ChildOf>), Without<ThirdPersonCamera>>,
+    velocity_q: Query<&CameraTargetVelocity>,
+    mut cam_q: Query<(
+        &ThirdPersonCamera,
+        &mut ThirdPersonCameraState,
+        &mut Transform,
+        Option<&ChildOf>,
+    )>,
 ) {
-    let Ok(player) = player_q.single() else {
-        return;
-    };
-    let Ok((cam, mut cam_transform)) = cam_q.single_mut() else {
-        return;
-    };
-
-    // Calculate the desired camera translation based, radius, and xy_offset
-    let rotation_matrix = Mat3::from_quat(cam_transform.rotation);
-
-    // apply the offset if offset_enabled is true
-    let mut offset = Vec3::ZERO;
-    if cam.offset_enabled {
-        offset = rotation_matrix.mul_vec3(Vec3::new(cam.offset.offset.0, cam.offset.offset.1, 0.0));
-    }
-
-    let desired_translation =
-        rotation_matrix.mul_vec3(Vec3::new(0.0, 0.0, cam.zoom.radius)) + offset;
-
-    // Update the camera translation
-    cam_transform.translation = desired_translation + player.translation;
-}
+    for (cam, mut state, mut cam_transform, cam_child_of) in cam_q.iter_mut() {
+        let dt = time.delta_secs(&state);
+        let cam_parent =
+            cam_child_of.and_then(|child_of| world_transform(child_of.parent(), &transform_q));
+
+        // a free flying camera is detached from its target
+        if state.free_fly {
+            let Some(position) = state.fly_position else {
+                continue;
+            };
+            state.current_yaw = state.yaw;
+            state.current_pitch = state.pitch;
+            let world = Transform::from_translation(position).with_rotation(state.rotation());
+            place_camera(world, cam_parent, &mut cam_transform);
+            continue;
+        }
 
-// only run aiming logic if `aim_enabled` is true
-fn aim_condition(cam_q: Query<&ThirdPersonCamera, With<ThirdPersonCamera>>) -> bool {
-    let Ok(cam) = cam_q.single() else {
-        return false;
-    };
-    cam.aim_enabled
-}
+        let Some(target) = cam.target_entity(&target_q) else {
+            continue;
+        };
+        // this runs before transform propagation, so the target's GlobalTransform would lag a frame behind
+        let Some(player) = world_transform(target, &transform_q) else {
+            continue;
+        };
+        let player = &player;
+
+        let state = &mut *state;
+        let target_point = cam.pivot_offset.point(player);
+        let velocity = velocity_q.get(target).ok().map(|velocity| velocity.0);
+        let pivot = state.look_ahead(&cam.look_ahead, target_point, velocity, dt);
+
+        // move the smoothed state towards the desired state
+        if state.snap {
+            state.look_ahead = Vec3::ZERO;
+            state.look_ahead_velocity = Vec3::ZERO;
+            state.current_yaw = state.yaw;
+            state.current_pitch = state.pitch;
+            state.pivot = target_point;
+            state.radius = cam.zoom.radius;
+            state.yaw_velocity = 0.0;
+            state.pitch_velocity = 0.0;
+            state.pivot_velocity = Vec3::ZERO;
+            state.radius_velocity = 0.0;
+            state.first_person_progress = if state.first_person { 1.0 } else { 0.0 };
+            state.snap = false;
+        } else {
+            let damping = &cam.damping;
+            state.current_yaw = damping.rotation.smooth_angle(
+                state.current_yaw,
+                state.yaw,
+                &mut state.yaw_velocity,
+                dt,
+            );
+            state.current_pitch = damping.rotation.smooth(
+                state.current_pitch,
+                state.pitch,
+                &mut state.pitch_velocity,
+                dt,
+            );
+            state.pivot =
+                damping
+                    .position
+                    .smooth(state.pivot, pivot, &mut state.pivot_velocity, dt);
+            state.radius = damping.zoom.smooth(
+                state.radius,
+                cam.zoom.radius,
+                &mut state.radius_velocity,
+                dt,
+            );
+        }
 
-#[allow(clippy::type_complexity)]
-fn aim(
-    time: Res<Time>,
-    gamepad_q: Query<&Gamepad>,
-    mouse: Res<ButtonInput<MouseButton>>,
-    mut cam_q: Query<
-        (&mut ThirdPersonCamera, &Transform),
-        (With<ThirdPersonCamera>, Without<ThirdPersonCameraTarget>),
-    >,
-    mut player_q: Query<&mut Transform, With<ThirdPersonCameraTarget>>,
-) {
-    let Ok((mut cam, cam_transform)) = cam_q.single_mut() else {
-        return;
-    };
+        // Calculate the desired camera translation based, radius, and xy_offset
+        let rotation = Quat::from_euler(EulerRot::YXZ, state.current_yaw, state.current_pitch, 0.0);
+        let rotation_matrix = Mat3::from_quat(rotation);
 
-    let Ok(mut player_transform) = player_q.single_mut() else {
-        return;
-    };
-    let gamepad = gamepad_q.single().ok();
+        // apply the offset if offset_enabled is true
+        let mut offset = Vec2::ZERO;
+        if cam.offset_enabled {
+            offset = Vec2::new(cam.offset.offset.0, cam.offset.offset.1);
+        }
 
-    // check if aim button was pressed
-    let is_gamepad_aiming = gamepad
-        .map(|g| g.pressed(cam.gamepad_settings.aim_button))
-        .unwrap_or_default();
-    let is_mouse_aiming = mouse.pressed(cam.aim_button);
+        // blend to the aim offset while aiming
+        if let Some(aim_offset) = cam.aim_offset {
+            let side = if cam.offset.is_transitioning {
+                -1.0
+            } else {
+                1.0
+            };
+            offset = offset.lerp(
+                Vec2::new(aim_offset.x * side, aim_offset.y),
+                state.aim_blend,
+            );
+        }
+        let offset = rotation_matrix.mul_vec3(offset.extend(0.0));
 
-    if is_mouse_aiming || is_gamepad_aiming {
-        // rotate player or target to face direction he is aiming
-        player_transform.look_to(*cam_transform.forward(), Vec3::Y);
+        let desired_translation =
+            rotation_matrix.mul_vec3(Vec3::new(0.0, 0.0, state.radius)) + offset;
 
-        let desired_zoom = cam.zoom.min * cam.aim_zoom;
+        // Update the camera transform
+        let mut world =
+            Transform::from_translation(desired_translation + state.pivot).with_rotation(rotation);
 
-        // radius_copy is used for restoring the radius (zoom) to it's
-        // original value after releasing the aim button
-        if cam.zoom.radius_copy.is_none() {
-            cam.zoom.radius_copy = Some(cam.zoom.radius);
+        // turn towards the look-at point, which follows the smoothed pivot
+        if let Some(look_at) = cam.look_at {
+            let look_at = state.pivot + look_at.point(player) - pivot;
+            world.look_at(look_at, Vec3::Y);
         }
 
-        let zoom_factor =
-            (cam.zoom.radius_copy.unwrap() / cam.aim_zoom) * cam.aim_speed * time.delta_secs();
-
-        // stop zooming in if current radius is less than desired zoom
-        if cam.zoom.radius <= desired_zoom || cam.zoom.radius - zoom_factor <= desired_zoom {
-            cam.zoom.radius = desired_zoom;
-        } else {
-            cam.zoom.radius -= zoom_factor;
+        state.blend_first_person(
+            cam.first_person.as_ref(),
+            |first_person| {
+                first_person
+                    .eye
+                    .and_then(|eye| world_transform(eye, &transform_q))
+                    .map_or_else(
+                        || first_person.eye_offset.point(player),
+                        |eye| eye.translation(),
+                    )
+            },
+            rotation,
+            dt,
+            &mut world,
+        );
+
+        // photo mode pans & rolls the camera, within a radius around the pivot
+        if let Some(photo) = &state.photo {
+            let arm = world.translation + photo.pan - state.pivot;
+            world.translation = state.pivot + arm.clamp_length_max(cam.photo_mode.radius);
+            world.rotation *= Quat::from_rotation_z(photo.roll);
         }
-    } else if let Some(radius_copy) = cam.zoom.radius_copy {
-        let zoom_factor = (radius_copy / cam.aim_zoom) * cam.aim_speed * time.delta_secs();
 
-        // stop zooming out if current radius is greater than original radius
-        if cam.zoom.radius >= radius_copy || cam.zoom.radius + zoom_factor >= radius_copy {
-            cam.zoom.radius = radius_copy;
-            cam.zoom.radius_copy = None;
-        } else {
-            cam.zoom.radius += (radius_copy / cam.aim_zoom) * cam.aim_speed * time.delta_secs();
+        place_camera(world, cam_parent, &mut cam_transform);
+    }
+}
+
+// the orbit is in world space, so a parented camera is placed relative to its parent
+fn place_camera(world: Transform, parent: Option<GlobalTransform>, cam_transform: &mut Transform) {
+    match parent {
+        Some(parent) => *cam_transform = GlobalTransform::from(world).reparented_to(&parent),
+        None => {
+            cam_transform.translation = world.translation;
+            cam_transform.rotation = world.rotation;
         }
     }
 }
 
+// only run zoom logic if at least one camera can currently zoom
 pub fn zoom_condition(cam_q: Query<&ThirdPersonCamera>) -> bool {
-    let Ok(cam) = cam_q.single() else {
-        return false;
-    };
-    cam.zoom_enabled && cam.cursor_lock_active
+    cam_q
+        .iter()
+        .any(|cam| cam.zoom_enabled && cam.cursor_lock_active)
 }
 
-// only run toggle_x_offset if `offset_toggle_enabled` is true
+// only run toggle_x_offset if `offset_toggle_enabled` is true for at least one camera
 fn toggle_x_offset_condition(cam_q: Query<&ThirdPersonCamera>) -> bool {
-    let Ok(cam) = cam_q.single() else {
-        return false;
-    };
-    cam.offset_toggle_enabled
+    cam_q.iter().any(|cam| cam.offset_toggle_enabled)
 }
 
 // inverts the x offset. Example: left shoulder view -> right shoulder view & vice versa
-fn toggle_x_offset(
-    time: Res<Time>,
-    btns: Query<&Gamepad>,
-    keys: Res<ButtonInput<KeyCode>>,
-    mut cam_q: Query<&mut ThirdPersonCamera>,
-) {
-    let Ok(mut cam) = cam_q.single_mut() else {
-        return;
-    };
-
-    let mut toggle_btn: bool = keys.just_pressed(cam.offset_toggle_key);
+fn toggle_x_offset(time: Res<Time>, mut cam_q: Query<(&mut ThirdPersonCamera, &CameraInput)>) {
+    for (mut cam, input) in cam_q.iter_mut() {
+        if !cam.offset_toggle_enabled {
+            continue;
+        }
 
-    for btns in btns.iter() {
         // check if toggle btn was pressed
-        toggle_btn = toggle_btn || btns.just_pressed(cam.gamepad_settings.offset_toggle_button);
-    }
-    if toggle_btn {
-        // Switch direction by inverting the offset_flag
-        cam.offset.is_transitioning = !cam.offset.is_transitioning;
-    }
+        if input.shoulder_swap {
+            // Switch direction by inverting the offset_flag
+            cam.offset.is_transitioning = !cam.offset.is_transitioning;
+        }
 
-    // Determine the transition speed based on direction
-    let transition_speed = if cam.offset.is_transitioning {
-        -cam.offset_toggle_speed
-    } else {
-        cam.offset_toggle_speed
-    };
+        // Determine the transition speed based on direction
+        let transition_speed = if cam.offset.is_transitioning {
+            -cam.offset_toggle_speed
+        } else {
+            cam.offset_toggle_speed
+        };
 
-    // Update the offset based on the direction and time
-    cam.offset.offset.0 = (cam.offset.offset.0 + transition_speed * time.delta_secs())
-        .clamp(-cam.offset.offset_copy.0, cam.offset.offset_copy.0);
+        // Update the offset based on the direction and time
+        cam.offset.offset.0 = (cam.offset.offset.0 + transition_speed * time.delta_secs())
+            .clamp(-cam.offset.offset_copy.0, cam.offset.offset_copy.0);
+    }
 }
 
 fn toggle_cursor(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut cam_q: Query<&mut ThirdPersonCamera>,
+    mut cam_q: Query<(&mut ThirdPersonCamera, &CameraInput)>,
     mut window_q: Query<&mut CursorOptions, With<PrimaryWindow>>,
 ) {
-    let Ok(mut cam) = cam_q.single_mut() else {
+    // there is only one cursor, so only cameras controlled by the keyboard & mouse can lock it
+    let mut lock_cursor = None;
+    for (mut cam, input) in cam_q.iter_mut() {
+        if !cam.keyboard_mouse_enabled || !cam.cursor_lock_toggle_enabled {
+            continue;
+        }
+
+        if input.cursor_toggle {
+            cam.cursor_lock_active = !cam.cursor_lock_active;
+        }
+        lock_cursor = Some(lock_cursor.unwrap_or_default() || cam.cursor_lock_active);
+    }
+
+    let Some(lock_cursor) = lock_cursor else {
         return;
     };
-    if keys.just_pressed(cam.cursor_lock_key) {
-        cam.cursor_lock_active = !cam.cursor_lock_active;
-    }
 
     if let Ok(mut cursor_options) = window_q.single_mut() {
-        if cam.cursor_lock_active {
+        if lock_cursor {
             cursor_options.grab_mode = CursorGrabMode::Locked;
             cursor_options.visible = false;
         } else {
@@ -428,8 +928,5 @@ fn toggle_cursor(
 
 // checks if the toggle cursor functionality is enabled
 fn toggle_cursor_condition(cam_q: Query<&ThirdPersonCamera>) -> bool {
-    let Ok(cam) = cam_q.single() else {
-        return true;
-    };
-    cam.cursor_lock_toggle_enabled
+    cam_q.iter().any(|cam| cam.cursor_lock_toggle_enabled)
 }
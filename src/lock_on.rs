@@ -0,0 +1,256 @@
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+
+use crate::{
+    CameraOcclusionProvider, OcclusionCast, ThirdPersonCamera, ThirdPersonCameraState,
+    ThirdPersonCameraTarget,
+    input::{CameraInput, read_camera_input},
+    orbit::orbit,
+};
+
+pub struct LockOnPlugin;
+
+impl Plugin for LockOnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            update_lock_on.after(read_camera_input).before(orbit),
+        );
+    }
+}
+
+/// Marks an entity the camera can lock on to.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::LockOnTarget;
+/// fn spawn_enemy(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::from_xyz(5.0, 0.0, 0.0),
+///         LockOnTarget {
+///             offset: Vec3::new(0.0, 1.2, 0.0), // chest height
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct LockOnTarget {
+    /// The point the camera looks at, relative to the entity.
+    /// Default is Vec3::ZERO
+    pub offset: Vec3,
+}
+
+/// Lock-on settings. While locked on, the camera stays behind its target, looking at the locked
+/// entity so both are in view. Orbit input is ignored while locked on.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{LockOn, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             lock_on: LockOn {
+///                 enabled: true,
+///                 range: 15.0,
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LockOn {
+    /// Flag to indicate if the lock-on functionality is turned on.
+    /// Default is false
+    pub enabled: bool,
+    /// The maximum distance between the camera target and a LockOnTarget. The lock is released
+    /// when the locked entity moves beyond this distance.
+    /// Default is 20.0
+    pub range: f32,
+    /// Added to the pitch while locked on, in radians. Negative values look down on both entities from above.
+    /// Default is -15 degrees
+    pub pitch_offset: f32,
+    /// Flag to indicate if the lock is released when the view of the locked entity is blocked.
+    /// Requires a CameraOcclusionProvider, such as the one added with the `avian3d` feature.
+    /// Default is true
+    pub line_of_sight: bool,
+    /// The time in seconds the view may be blocked before the lock is released.
+    /// Default is 0.5
+    pub line_of_sight_grace: f32,
+}
+
+impl Default for LockOn {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            range: 20.0,
+            pitch_offset: (-15.0_f32).to_radians(),
+            line_of_sight: true,
+            line_of_sight_grace: 0.5,
+        }
+    }
+}
+
+struct Candidate {
+    entity: Entity,
+    point: Vec3,
+    // horizontal angle from the center of the screen, positive to the right
+    angle: f32,
+    // angle between the camera's forward & the direction to the point
+    screen_angle: f32,
+}
+
+pub(crate) fn update_lock_on(
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    player_q: Query<&GlobalTransform, Without<ThirdPersonCamera>>,
+    candidate_q: Query<(Entity, &LockOnTarget, &GlobalTransform)>,
+    mut cam_q: Query<(
+        &ThirdPersonCamera,
+        &CameraInput,
+        &mut ThirdPersonCameraState,
+        &GlobalTransform,
+    )>,
+) {
+    for (cam, input, mut state, cam_global) in cam_q.iter_mut() {
+        let lock_on = &cam.lock_on;
+        let Some(target) = cam
+            .target_entity(&target_q)
+            .filter(|_| lock_on.enabled && !state.free_fly && !state.is_in_photo_mode())
+        else {
+            state.locked_target = None;
+            continue;
+        };
+        let Ok(player) = player_q.get(target) else {
+            state.locked_target = None;
+            continue;
+        };
+
+        let pivot = cam.pivot_offset.point(player);
+        // measured from the camera, so the shoulder offset & pitch are taken into account
+        let eye = cam_global.translation();
+        let forward = cam_global.forward();
+        let right = cam_global.right();
+
+        let candidates: Vec<Candidate> = candidate_q
+            .iter()
+            .filter(|(entity, ..)| *entity != target)
+            .map(|(entity, lock_target, transform)| {
+                let point = transform.transform_point(lock_target.offset);
+                let direction = point - eye;
+                Candidate {
+                    entity,
+                    point,
+                    angle: f32::atan2(direction.dot(*right), direction.dot(*forward)),
+                    screen_angle: direction.angle_between(*forward),
+                }
+            })
+            .filter(|candidate| candidate.point.distance(pivot) <= lock_on.range)
+            .collect();
+
+        // release the lock when the entity is gone (e.g. died), out of range or out of sight
+        if let Some(locked) = state.locked_target {
+            let in_range = candidates.iter().any(|c| c.entity == locked);
+            if !in_range || state.line_of_sight_lost_secs > lock_on.line_of_sight_grace {
+                state.locked_target = None;
+            }
+        }
+
+        if input.lock_on {
+            state.line_of_sight_lost_secs = 0.0;
+            state.locked_target = match state.locked_target {
+                Some(_) => None,
+                // pick the candidate closest to the center of the screen
+                None => candidates
+                    .iter()
+                    .filter(|c| c.screen_angle < std::f32::consts::FRAC_PI_2)
+                    .min_by(|a, b| a.screen_angle.total_cmp(&b.screen_angle))
+                    .map(|c| c.entity),
+            };
+        } else if let Some(locked) = state.locked_target
+            && (input.lock_on_next || input.lock_on_previous)
+            && let Some(current) = candidates.iter().find(|c| c.entity == locked)
+        {
+            // cycle through the candidates from left to right, wrapping around at the edges
+            let next = if input.lock_on_next {
+                candidates
+                    .iter()
+                    .filter(|c| c.angle > current.angle)
+                    .min_by(|a, b| a.angle.total_cmp(&b.angle))
+                    .or_else(|| candidates.iter().min_by(|a, b| a.angle.total_cmp(&b.angle)))
+            } else {
+                candidates
+                    .iter()
+                    .filter(|c| c.angle < current.angle)
+                    .max_by(|a, b| a.angle.total_cmp(&b.angle))
+                    .or_else(|| candidates.iter().max_by(|a, b| a.angle.total_cmp(&b.angle)))
+            };
+            state.line_of_sight_lost_secs = 0.0;
+            state.locked_target = next.map(|c| c.entity);
+        }
+
+        // look from behind the camera target towards the locked entity
+        let Some(locked) = state
+            .locked_target
+            .and_then(|locked| candidates.iter().find(|c| c.entity == locked))
+        else {
+            continue;
+        };
+        let direction = locked.point - pivot;
+        let yaw = f32::atan2(-direction.x, -direction.z);
+        let pitch = f32::atan2(direction.y, direction.xz().length()) + lock_on.pitch_offset;
+        state.set_rotation(
+            yaw,
+            pitch.clamp(cam.min_pitch, cam.max_pitch.max(cam.min_pitch)),
+        );
+    }
+}
+
+// measures how long the view of the locked entity has been blocked
+pub(crate) fn check_line_of_sight<P: CameraOcclusionProvider>(
+    time: Res<Time>,
+    mut occlusion: StaticSystemParam<P::Param>,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    player_q: Query<&GlobalTransform, Without<ThirdPersonCamera>>,
+    candidate_q: Query<(&LockOnTarget, &GlobalTransform)>,
+    mut cam_q: Query<(Entity, &ThirdPersonCamera, &mut ThirdPersonCameraState)>,
+) {
+    for (cam_entity, cam, mut state) in cam_q.iter_mut() {
+        let Some(locked) = state.locked_target.filter(|_| cam.lock_on.line_of_sight) else {
+            state.line_of_sight_lost_secs = 0.0;
+            continue;
+        };
+        let Some(target) = cam.target_entity(&target_q) else {
+            continue;
+        };
+        let (Ok(player), Ok((lock_target, locked_transform))) =
+            (player_q.get(target), candidate_q.get(locked))
+        else {
+            continue;
+        };
+
+        let from = cam.pivot_offset.point(player);
+        let to = locked_transform.transform_point(lock_target.offset);
+        let blocked = Dir3::new(to - from).is_ok_and(|direction| {
+            let cast = OcclusionCast {
+                camera: cam_entity,
+                origin: from,
+                direction,
+                max_distance: from.distance(to),
+                radius: 0.0,
+                excluded: &[target, locked],
+            };
+            P::cast(&mut occlusion, &cast).is_some()
+        });
+
+        if blocked {
+            state.line_of_sight_lost_secs += time.delta_secs();
+        } else {
+            state.line_of_sight_lost_secs = 0.0;
+        }
+    }
+}
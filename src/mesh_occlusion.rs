@@ -0,0 +1,87 @@
+use bevy::{
+    ecs::system::SystemParamItem,
+    picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility},
+    prelude::*,
+};
+
+use crate::{CameraOcclusionProvider, OcclusionCast};
+
+/// Camera collision & lock-on line of sight checks against visible meshes, using Bevy's mesh ray
+/// casting. Works without a physics engine. Requires the `mesh_picking` feature.
+///
+/// Mesh ray casts can't sweep a sphere, so a sphere cast is approximated with a ray through its
+/// center & four rays along its edge. The camera target and all of its descendants are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{CameraOcclusionPlugin, MeshOcclusion, ThirdPersonCameraPlugin};
+/// App::new().add_plugins((
+///     ThirdPersonCameraPlugin,
+///     CameraOcclusionPlugin::<MeshOcclusion>::default(),
+/// ));
+/// ```
+pub struct MeshOcclusion;
+
+impl CameraOcclusionProvider for MeshOcclusion {
+    type Param = (
+        MeshRayCast<'static, 'static>,
+        Query<'static, 'static, &'static ChildOf>,
+    );
+
+    fn cast(
+        (ray_cast, parent_q): &mut SystemParamItem<Self::Param>,
+        cast: &OcclusionCast,
+    ) -> Option<f32> {
+        let filter = |entity| !cast.is_excluded(entity, parent_q);
+        let settings = MeshRayCastSettings::default()
+            .with_visibility(RayCastVisibility::Visible)
+            .with_filter(&filter);
+
+        rays(cast)
+            .filter_map(|ray| {
+                ray_cast
+                    .cast_ray(ray, &settings)
+                    .first()
+                    .map(|(_, hit)| hit.distance)
+            })
+            // keep the sphere from touching the hit surface
+            .map(|distance| (distance - cast.radius).max(0.0))
+            .filter(|distance| *distance <= cast.max_distance)
+            .min_by(f32::total_cmp)
+    }
+
+    fn cast_all(
+        (ray_cast, parent_q): &mut SystemParamItem<Self::Param>,
+        cast: &OcclusionCast,
+        hits: &mut Vec<Entity>,
+    ) {
+        let filter = |entity| !cast.is_excluded(entity, parent_q);
+        let settings = MeshRayCastSettings::default()
+            .with_visibility(RayCastVisibility::Visible)
+            .with_filter(&filter)
+            .never_early_exit();
+
+        for ray in rays(cast) {
+            let ray_hits = ray_cast.cast_ray(ray, &settings);
+            hits.extend(
+                ray_hits
+                    .iter()
+                    .filter(|(_, hit)| hit.distance <= cast.max_distance)
+                    .map(|(entity, _)| *entity),
+            );
+        }
+    }
+}
+
+// a ray through the center of the cast sphere & four rays along its edge
+fn rays(cast: &OcclusionCast) -> impl Iterator<Item = Ray3d> {
+    let (up, right) = cast.direction.any_orthonormal_pair();
+    let count = if cast.radius > 0.0 { 5 } else { 1 };
+    let (origin, direction, radius) = (cast.origin, cast.direction, cast.radius);
+    [Vec3::ZERO, up, -up, right, -right]
+        .into_iter()
+        .take(count)
+        .map(move |offset| Ray3d::new(origin + offset * radius, direction))
+}
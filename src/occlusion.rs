@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::system::{SystemParam, SystemParamItem},
+    prelude::*,
+};
+
+use crate::{
+    CameraSyncSystems,
+    collision::avoid_collisions,
+    fade::find_occluders,
+    input::read_camera_input,
+    lock_on::{check_line_of_sight, update_lock_on},
+};
+
+/// Adds camera collision & lock-on line of sight checks, using the given occlusion provider.
+/// The provider for avian3d is added automatically with the `avian3d` feature. Only add one provider.
+///
+/// # Examples
+///
+/// ```ignore
+/// App::new().add_plugins((
+///     ThirdPersonCameraPlugin,
+///     CameraOcclusionPlugin::<MyOcclusion>::default(),
+/// ));
+/// ```
+pub struct CameraOcclusionPlugin<P: CameraOcclusionProvider>(PhantomData<P>);
+
+impl<P: CameraOcclusionProvider> Default for CameraOcclusionPlugin<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: CameraOcclusionProvider> Plugin for CameraOcclusionPlugin<P> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            check_line_of_sight::<P>
+                .after(read_camera_input)
+                .before(update_lock_on),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                avoid_collisions::<P>.in_set(CameraSyncSystems::Collision),
+                find_occluders::<P>.in_set(CameraSyncSystems::Fade),
+            ),
+        );
+    }
+}
+
+/// Finds what blocks the view of the camera, so the camera isn't tied to a specific physics engine.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::{ecs::system::SystemParamItem, prelude::*};
+/// use bevy_third_person_camera::{CameraOcclusionProvider, OcclusionCast};
+///
+/// // the ground plane at y = 0 is the only thing that blocks the camera
+/// struct GroundOcclusion;
+///
+/// impl CameraOcclusionProvider for GroundOcclusion {
+///     type Param = ();
+///
+///     fn cast(_: &mut SystemParamItem<Self::Param>, cast: &OcclusionCast) -> Option<f32> {
+///         let height = cast.origin.y - cast.radius;
+///         if cast.direction.y >= 0.0 || height < 0.0 {
+///             return None;
+///         }
+///         let distance = height / -cast.direction.y;
+///         (distance <= cast.max_distance).then_some(distance)
+///     }
+///
+///     // the ground isn't an entity, so there is nothing to fade
+///     fn cast_all(_: &mut SystemParamItem<Self::Param>, _: &OcclusionCast, _: &mut Vec<Entity>) {}
+/// }
+/// ```
+pub trait CameraOcclusionProvider: Send + Sync + 'static {
+    /// The system parameters needed to perform casts, e.g. a physics engine's spatial query.
+    type Param: SystemParam + 'static;
+
+    /// Returns the distance to the first hit along the cast, if any.
+    fn cast(param: &mut SystemParamItem<Self::Param>, cast: &OcclusionCast) -> Option<f32>;
+
+    /// Collects every entity hit along the cast, used to fade CameraFadeable occluders.
+    fn cast_all(
+        param: &mut SystemParamItem<Self::Param>,
+        cast: &OcclusionCast,
+        hits: &mut Vec<Entity>,
+    );
+}
+
+/// A sphere or ray cast performed by a CameraOcclusionProvider.
+#[derive(Clone, Copy, Debug)]
+pub struct OcclusionCast<'a> {
+    /// The camera the cast is performed for.
+    pub camera: Entity,
+    pub origin: Vec3,
+    pub direction: Dir3,
+    pub max_distance: f32,
+    /// The radius of the cast sphere. 0.0 casts a ray.
+    pub radius: f32,
+    /// Entities that never block the view, along with their descendants, such as the camera target.
+    pub excluded: &'a [Entity],
+}
+
+impl OcclusionCast<'_> {
+    /// Whether the entity, or one of its ancestors, is excluded from the cast, such as the meshes
+    /// & colliders of the target's scene.
+    pub fn is_excluded(&self, entity: Entity, parent_q: &Query<&ChildOf>) -> bool {
+        std::iter::once(entity)
+            .chain(parent_q.iter_ancestors(entity))
+            .any(|entity| self.excluded.contains(&entity))
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCamera, ThirdPersonCameraState, ThirdPersonCameraTarget, input::CameraInput,
+    input::read_camera_input, target_yaw, time::CameraTime, zoom_condition,
+};
+
+pub struct OrbitPlugin;
+
+impl Plugin for OrbitPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, orbit.after(read_camera_input))
+            .add_systems(Update, zoom.run_if(zoom_condition));
+    }
+}
+
+// heavily referenced https://bevy-cheatbook.github.io/cookbook/pan-orbit-camera.html
+pub fn orbit(
+    time: CameraTime,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    player_q: Query<&GlobalTransform, Without<ThirdPersonCamera>>,
+    mut cam_q: Query<(
+        &ThirdPersonCamera,
+        &CameraInput,
+        &mut ThirdPersonCameraState,
+    )>,
+) {
+    for (cam, input, mut state) in cam_q.iter_mut() {
+        // the lock-on drives the rotation while locked
+        if state.locked_target.is_some() {
+            continue;
+        }
+
+        // mouse movement is measured in counts, so it is independent of the frame rate
+        let mut delta = input.orbit_delta * cam.sensitivity;
+
+        // sticks & buttons are a rate, so they are scaled by the frame time
+        let dt = time.delta_secs(&state);
+        let rotation = Vec2::new(input.orbit_rate.x, -input.orbit_rate.y);
+        delta += rotation * cam.gamepad_settings.sensitivity * dt;
+
+        // sensitivities are in degrees
+        let mut delta = Vec2::new(delta.x.to_radians(), delta.y.to_radians());
+
+        if cam.mouse_orbit_button_enabled && !input.orbit_button {
+            delta = Vec2::ZERO;
+        }
+
+        let player = cam
+            .target_entity(&target_q)
+            .and_then(|target| player_q.get(target).ok());
+        let target_yaw = player.map(target_yaw);
+
+        let mut delta_yaw = -delta.x;
+        let mut delta_pitch = -delta.y;
+
+        // player input always cancels recentering
+        if let Some(player) = player.filter(|_| !state.free_fly && !state.is_in_photo_mode()) {
+            let rotation = state.recenter(cam, input, player, delta, dt);
+            delta_yaw += rotation.x;
+            delta_pitch += rotation.y;
+        }
+
+        // always applied so the limits hold even without input, e.g. when the target turns
+        state.orbit(cam, delta_yaw, delta_pitch, target_yaw);
+    }
+}
+
+// sets the target radius, the camera eases towards it in sync_player_camera
+fn zoom(
+    time: CameraTime,
+    mut cam_q: Query<(
+        &mut ThirdPersonCamera,
+        &CameraInput,
+        &mut ThirdPersonCameraState,
+    )>,
+) {
+    for (mut cam, input, mut state) in cam_q.iter_mut() {
+        // the aim zoom drives the radius while aiming
+        if !cam.zoom_enabled || !cam.cursor_lock_active || cam.zoom.radius_copy.is_some() {
+            continue;
+        }
+
+        if input.zoom_step
+            && let Some(step) = cam.zoom.next_step()
+        {
+            cam.zoom.radius = step;
+            state.first_person = false;
+            continue;
+        }
+
+        // zooming in past the minimum radius switches to first person, zooming out switches back
+        if cam.first_person.is_some() {
+            let zoom_direction = input.zoom_delta + input.zoom_rate;
+            if state.first_person {
+                if zoom_direction < 0.0 {
+                    state.first_person = false;
+                }
+                continue;
+            }
+            if zoom_direction > 0.0 && cam.zoom.radius <= cam.zoom.min {
+                state.first_person = true;
+                continue;
+            }
+        }
+
+        let mut new_radius = cam.zoom.radius;
+
+        // scroll wheel zooms 10% per step, sticks & buttons are a rate scaled by the frame time
+        new_radius -= input.zoom_delta * cam.zoom.radius * 0.1 * cam.zoom_sensitivity;
+        new_radius -= input.zoom_rate * cam.zoom.radius * cam.zoom_speed * time.delta_secs(&state);
+
+        cam.zoom.radius = new_radius.clamp(cam.zoom.min, cam.zoom.max);
+    }
+}
@@ -0,0 +1,263 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "depth_of_field")]
+use bevy::post_process::dof::DepthOfField;
+
+use crate::{
+    ThirdPersonCamera, ThirdPersonCameraState, damping::wrap_angle, input::CameraInput,
+    lock_on::update_lock_on, orbit::orbit,
+};
+
+pub struct PhotoPlugin;
+
+impl Plugin for PhotoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoPause>().add_systems(
+            PreUpdate,
+            photo_mode
+                .after(orbit)
+                .after(update_lock_on)
+                .run_if(photo_mode_condition),
+        );
+    }
+}
+
+/// Photo mode settings. Photo mode pauses the game, so players can frame screenshots: the camera
+/// keeps orbiting & zooming on real time, and can be panned, rolled and have its field of view &
+/// depth of field changed. Leaving photo mode restores the camera exactly as it was.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{PhotoMode, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             photo_mode: PhotoMode {
+///                 enabled: true,
+///                 radius: 8.0,
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhotoMode {
+    /// Flag to indicate if the photo mode action can enter photo mode.
+    /// Default is false
+    pub enabled: bool,
+    /// The farthest the camera can move from the pivot, by zooming or panning.
+    /// Default is 5.0
+    pub radius: f32,
+    /// Flag to indicate if virtual time is paused while in photo mode, freezing the game.
+    /// Default is true
+    pub pause_time: bool,
+    /// The speed at which the camera pans, in units per second.
+    /// Default is 2.0
+    pub pan_speed: f32,
+    /// The speed at which the camera rolls, in radians per second.
+    /// Default is 45 degrees
+    pub roll_speed: f32,
+    /// The narrowest vertical field of view, in radians. Requires a perspective Projection.
+    /// Default is 10 degrees
+    pub min_fov: f32,
+    /// The widest vertical field of view, in radians.
+    /// Default is 100 degrees
+    pub max_fov: f32,
+    /// The speed at which the field of view changes, in radians per second.
+    /// Default is 30 degrees
+    pub fov_speed: f32,
+    /// The speed at which the focus distance changes, in units per second. Only used with the `depth_of_field` feature.
+    /// Default is 2.0
+    pub focus_speed: f32,
+    /// The speed at which the aperture changes, in f-stops per second. Only used with the `depth_of_field` feature.
+    /// Default is 2.0
+    pub aperture_speed: f32,
+}
+
+impl Default for PhotoMode {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            radius: 5.0,
+            pause_time: true,
+            pan_speed: 2.0,
+            roll_speed: 45.0_f32.to_radians(),
+            min_fov: 10.0_f32.to_radians(),
+            max_fov: 100.0_f32.to_radians(),
+            fov_speed: 30.0_f32.to_radians(),
+            focus_speed: 2.0,
+            aperture_speed: 2.0,
+        }
+    }
+}
+
+// a photo mode session, along with everything that is restored when it ends
+#[derive(Clone)]
+pub(crate) struct PhotoSession {
+    state: ThirdPersonCameraState,
+    zoom_radius: f32,
+    transform: Transform,
+    projection: Option<Projection>,
+    #[cfg(feature = "depth_of_field")]
+    depth_of_field: Option<DepthOfField>,
+    // whether this camera keeps virtual time paused
+    pause_time: bool,
+    pub(crate) pan: Vec3,
+    pub(crate) roll: f32,
+}
+
+// whether virtual time was paused by photo mode, rather than already paused. It stays paused
+// until no camera in photo mode asks for the pause
+#[derive(Resource, Default)]
+struct PhotoPause(bool);
+
+// enters & leaves photo mode, and applies the photo controls
+fn photo_mode(
+    #[cfg(feature = "depth_of_field")] mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut pause: ResMut<PhotoPause>,
+    mut cam_q: Query<(
+        Entity,
+        &mut ThirdPersonCamera,
+        &CameraInput,
+        &mut ThirdPersonCameraState,
+        &mut Transform,
+    )>,
+    mut projection_q: Query<&mut Projection>,
+    #[cfg(feature = "depth_of_field")] mut dof_q: Query<&mut DepthOfField>,
+) {
+    let dt = real_time.delta_secs();
+    for (cam_entity, mut cam, input, mut state, mut cam_transform) in cam_q.iter_mut() {
+        let state = &mut *state;
+        let projection = projection_q.get_mut(cam_entity).ok();
+        if input.photo_mode {
+            state.photo_requested = !state.photo_requested;
+        }
+
+        // enter photo mode, remembering the camera as it is
+        if state.photo_requested && state.photo.is_none() {
+            if state.free_fly {
+                state.photo_requested = false;
+                continue;
+            }
+
+            let mut restore = state.clone();
+            restore.photo_requested = false;
+            #[cfg(feature = "depth_of_field")]
+            let depth_of_field = dof_q.get(cam_entity).ok().copied();
+            #[cfg(feature = "depth_of_field")]
+            if depth_of_field.is_none() {
+                commands.entity(cam_entity).insert(DepthOfField {
+                    focal_distance: state.radius.max(0.1),
+                    ..default()
+                });
+            }
+
+            state.photo = Some(Box::new(PhotoSession {
+                state: restore,
+                zoom_radius: cam.zoom.radius,
+                transform: *cam_transform,
+                projection: projection.as_deref().cloned(),
+                #[cfg(feature = "depth_of_field")]
+                depth_of_field,
+                pause_time: cam.photo_mode.pause_time,
+                pan: Vec3::ZERO,
+                roll: 0.0,
+            }));
+            continue;
+        }
+
+        // leave photo mode, restoring the camera exactly as it was
+        if !state.photo_requested
+            && let Some(session) = state.photo.take()
+        {
+            let session = *session;
+            // the target's visibility may have changed since, so it keeps being tracked
+            let (near_hidden, near_faded) = (state.near_hidden, state.near_faded);
+            *state = session.state;
+            state.near_hidden = near_hidden;
+            state.near_faded = near_faded;
+
+            cam.zoom.radius = session.zoom_radius;
+            *cam_transform = session.transform;
+            if let (Some(mut projection), Some(restore)) = (projection, session.projection) {
+                *projection = restore;
+            }
+            #[cfg(feature = "depth_of_field")]
+            match session.depth_of_field {
+                Some(depth_of_field) => {
+                    commands.entity(cam_entity).insert(depth_of_field);
+                }
+                None => {
+                    commands.entity(cam_entity).remove::<DepthOfField>();
+                }
+            }
+            continue;
+        }
+
+        let rotation = state.rotation();
+        let Some(photo) = state.photo.as_mut() else {
+            continue;
+        };
+        let settings = &cam.photo_mode;
+
+        // pan in the plane of the screen, within the photo mode radius
+        let pan = rotation * Vec3::new(input.fly_rate.x, input.fly_rate.z, 0.0);
+        photo.pan = (photo.pan + pan * settings.pan_speed * dt).clamp_length_max(settings.radius);
+        photo.roll = wrap_angle(photo.roll + input.roll_rate * settings.roll_speed * dt);
+
+        if let Some(mut projection) = projection
+            && let Projection::Perspective(perspective) = &mut *projection
+            && input.fov_rate != 0.0
+        {
+            perspective.fov = (perspective.fov - input.fov_rate * settings.fov_speed * dt)
+                .clamp(settings.min_fov, settings.max_fov.max(settings.min_fov));
+        }
+
+        #[cfg(feature = "depth_of_field")]
+        if let Ok(mut depth_of_field) = dof_q.get_mut(cam_entity)
+            && (input.focus_rate != 0.0 || input.aperture_rate != 0.0)
+        {
+            depth_of_field.focal_distance = (depth_of_field.focal_distance
+                + input.focus_rate * settings.focus_speed * dt)
+                .max(0.1);
+            // each f-stop is a factor of √2
+            let stops = input.aperture_rate * settings.aperture_speed * dt;
+            depth_of_field.aperture_f_stops =
+                (depth_of_field.aperture_f_stops * 2.0_f32.powf(stops / 2.0)).clamp(0.5, 64.0);
+        }
+    }
+
+    // virtual time is paused while any camera in photo mode asks for it, unless something else paused it
+    let pause_time = cam_q.iter().any(|(_, _, _, state, _)| {
+        state
+            .photo
+            .as_ref()
+            .is_some_and(|session| session.pause_time)
+    });
+    if pause_time && !pause.0 && !virtual_time.is_paused() {
+        virtual_time.pause();
+        pause.0 = true;
+    } else if !pause_time && pause.0 {
+        virtual_time.unpause();
+        pause.0 = false;
+    }
+}
+
+// only run photo mode logic if at least one camera can, or already does, use photo mode, or
+// time is still paused by a camera that is gone
+fn photo_mode_condition(
+    pause: Res<PhotoPause>,
+    cam_q: Query<(&ThirdPersonCamera, &ThirdPersonCameraState)>,
+) -> bool {
+    pause.0
+        || cam_q.iter().any(|(cam, state)| {
+            cam.photo_mode.enabled || state.photo_requested || state.photo.is_some()
+        })
+}
@@ -0,0 +1,225 @@
+use avian3d::prelude::*;
+use bevy::{ecs::system::SystemParamItem, prelude::*};
+
+use crate::{
+    CameraOcclusionPlugin, CameraOcclusionProvider, CameraSyncSet, CameraTargetVelocity,
+    OcclusionCast, ThirdPersonCamera, ThirdPersonCameraTarget,
+};
+
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(CameraOcclusionPlugin::<AvianOcclusion>::default())
+            // physics runs in FixedPostUpdate by default, but may be moved to PostUpdate
+            .configure_sets(PostUpdate, CameraSyncSet.after(PhysicsSystems::Last))
+            .add_systems(Update, (ease_physics_targets, copy_target_velocity));
+    }
+}
+
+/// Camera collision & lock-on line of sight checks using avian3d's spatial queries. Added
+/// automatically with the `avian3d` feature. Use the AvianOcclusionFilter component to choose
+/// which colliders block the camera.
+pub struct AvianOcclusion;
+
+impl CameraOcclusionProvider for AvianOcclusion {
+    type Param = (
+        Option<SpatialQuery<'static, 'static>>,
+        Query<'static, 'static, &'static AvianOcclusionFilter>,
+        Query<'static, 'static, &'static ChildOf>,
+    );
+
+    fn cast(
+        (spatial_query, filter_q, parent_q): &mut SystemParamItem<Self::Param>,
+        cast: &OcclusionCast,
+    ) -> Option<f32> {
+        let spatial_query = spatial_query.as_ref()?;
+        let filter = filter_q
+            .get(cast.camera)
+            .map_or_else(|_| SpatialQueryFilter::default(), |filter| filter.0.clone());
+        // colliders are often children of the excluded entity, e.g. in a scene
+        let predicate = |entity| !cast.is_excluded(entity, parent_q);
+
+        if cast.radius > 0.0 {
+            spatial_query
+                .cast_shape_predicate(
+                    &Collider::sphere(cast.radius),
+                    cast.origin,
+                    Quat::IDENTITY,
+                    cast.direction,
+                    &ShapeCastConfig::from_max_distance(cast.max_distance),
+                    &filter,
+                    &predicate,
+                )
+                .map(|hit| hit.distance)
+        } else {
+            spatial_query
+                .cast_ray_predicate(
+                    cast.origin,
+                    cast.direction,
+                    cast.max_distance,
+                    true,
+                    &filter,
+                    &predicate,
+                )
+                .map(|hit| hit.distance)
+        }
+    }
+
+    // the AvianOcclusionFilter is ignored, so occluders can fade without blocking the camera
+    fn cast_all(
+        (spatial_query, _, parent_q): &mut SystemParamItem<Self::Param>,
+        cast: &OcclusionCast,
+        hits: &mut Vec<Entity>,
+    ) {
+        let Some(spatial_query) = spatial_query.as_ref() else {
+            return;
+        };
+        let filter = SpatialQueryFilter::default();
+
+        if cast.radius > 0.0 {
+            spatial_query.shape_hits_callback(
+                &Collider::sphere(cast.radius),
+                cast.origin,
+                Quat::IDENTITY,
+                cast.direction,
+                &ShapeCastConfig::from_max_distance(cast.max_distance),
+                &filter,
+                |hit| {
+                    if !cast.is_excluded(hit.entity, parent_q) {
+                        hits.push(hit.entity);
+                    }
+                    true
+                },
+            );
+        } else {
+            spatial_query.ray_hits_callback(
+                cast.origin,
+                cast.direction,
+                cast.max_distance,
+                true,
+                &filter,
+                |hit| {
+                    if !cast.is_excluded(hit.entity, parent_q) {
+                        hits.push(hit.entity);
+                    }
+                    true
+                },
+            );
+        }
+    }
+}
+
+/// Determines which colliders block a camera, when added to the camera entity. The camera target
+/// is always ignored.
+///
+/// # Examples
+///
+/// ```
+/// use avian3d::prelude::*;
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{AvianOcclusionFilter, CameraCollision, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera::default(),
+///         CameraCollision::default(),
+///         AvianOcclusionFilter(SpatialQueryFilter::from_mask(LayerMask::ALL)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default)]
+pub struct AvianOcclusionFilter(pub SpatialQueryFilter);
+
+/// How the camera follows a target driven by avian3d. Rigid bodies move in fixed timesteps, so
+/// following them directly stutters whenever the frame rate doesn't match the fixed timestep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PhysicsFollow {
+    /// Adds TransformInterpolation to the target's rigid body, so its Transform is smoothly
+    /// interpolated between the last two physics steps. Adds a fixed timestep of latency.
+    #[default]
+    Interpolate,
+    /// Adds TransformExtrapolation to the target's rigid body, so its Transform is predicted
+    /// from its velocity. No added latency, but may overshoot when the body changes direction.
+    Extrapolate,
+    /// Follows the target's Transform as is. Use this when the target is already eased, or isn't a rigid body.
+    Off,
+}
+
+// adds transform easing to rigid bodies followed by a camera, unless they are already eased
+fn ease_physics_targets(
+    mut commands: Commands,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    cam_q: Query<&ThirdPersonCamera>,
+    parent_q: Query<&ChildOf>,
+    body_q: Query<(Has<TransformInterpolation>, Has<TransformExtrapolation>), With<RigidBody>>,
+) {
+    for cam in cam_q.iter() {
+        if cam.physics_follow == PhysicsFollow::Off {
+            continue;
+        }
+        let Some(target) = cam.target_entity(&target_q) else {
+            continue;
+        };
+
+        let Some(body) = rigid_body(target, &parent_q, |entity| body_q.contains(entity)) else {
+            continue;
+        };
+        let Ok((interpolated, extrapolated)) = body_q.get(body) else {
+            continue;
+        };
+        if interpolated || extrapolated {
+            continue;
+        }
+        match cam.physics_follow {
+            PhysicsFollow::Extrapolate => {
+                commands.entity(body).insert(TransformExtrapolation);
+            }
+            _ => {
+                commands.entity(body).insert(TransformInterpolation);
+            }
+        }
+    }
+}
+
+// keeps the velocity used for look-ahead in sync with the followed rigid body
+fn copy_target_velocity(
+    mut commands: Commands,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    cam_q: Query<&ThirdPersonCamera>,
+    parent_q: Query<&ChildOf>,
+    body_q: Query<&LinearVelocity, With<RigidBody>>,
+    mut velocity_q: Query<&mut CameraTargetVelocity>,
+) {
+    for cam in cam_q.iter() {
+        let Some(target) = cam.target_entity(&target_q) else {
+            continue;
+        };
+        let Some(velocity) = rigid_body(target, &parent_q, |entity| body_q.contains(entity))
+            .and_then(|body| body_q.get(body).ok())
+        else {
+            continue;
+        };
+
+        match velocity_q.get_mut(target) {
+            Ok(mut target_velocity) => target_velocity.0 = velocity.0,
+            Err(_) => {
+                commands
+                    .entity(target)
+                    .insert(CameraTargetVelocity(velocity.0));
+            }
+        }
+    }
+}
+
+// the rigid body moving the target: the target itself, or one of its ancestors, e.g. for a mount point
+fn rigid_body(
+    mut entity: Entity,
+    parent_q: &Query<&ChildOf>,
+    is_body: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    while !is_body(entity) {
+        entity = parent_q.get(entity).ok()?.parent();
+    }
+    Some(entity)
+}
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use crate::{Damping, ThirdPersonCameraState};
+
+/// An offset from the camera target, either in world space or in the target's local space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TargetOffset {
+    /// An offset in world space. Doesn't rotate with the target.
+    World(Vec3),
+    /// An offset in the target's local space. Rotates with the target, e.g. to stay above a leaning vehicle.
+    Local(Vec3),
+}
+
+impl Default for TargetOffset {
+    fn default() -> Self {
+        TargetOffset::World(Vec3::ZERO)
+    }
+}
+
+impl TargetOffset {
+    /// The offset point for a target with the given world transform.
+    pub fn point(&self, target: &GlobalTransform) -> Vec3 {
+        match *self {
+            TargetOffset::World(offset) => target.translation() + offset,
+            TargetOffset::Local(offset) => target.translation() + target.rotation() * offset,
+        }
+    }
+}
+
+/// Moves the pivot ahead of the target in the direction it is moving. The target's velocity is
+/// taken from its CameraTargetVelocity component when it has one, and estimated from its movement otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{LookAhead, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             look_ahead: LookAhead {
+///                 time: 0.3,
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LookAhead {
+    /// How far ahead the pivot leads, in seconds of the target's velocity. 0.0 disables look-ahead.
+    /// Default is 0.0
+    pub time: f32,
+    /// The maximum distance between the target and the pivot.
+    /// Default is 1.5
+    pub max_distance: f32,
+    /// Flag to indicate if vertical movement (e.g. jumping & falling) is also led.
+    /// Default is false
+    pub vertical: bool,
+    /// Smoothing of the look-ahead, so it doesn't jump when the target changes direction.
+    /// Default is Damping::Exponential(3.0)
+    pub damping: Damping,
+}
+
+impl Default for LookAhead {
+    fn default() -> Self {
+        Self {
+            time: 0.0,
+            max_distance: 1.5,
+            vertical: false,
+            damping: Damping::Exponential(3.0),
+        }
+    }
+}
+
+/// The velocity of a camera target, used for look-ahead. Kept up to date for rigid bodies with the
+/// `avian3d` feature. Add and update it from a character controller or another physics engine,
+/// otherwise the velocity is estimated from the target's movement.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct CameraTargetVelocity(pub Vec3);
+
+impl ThirdPersonCameraState {
+    /// Leads the target in the direction it is moving, using its CameraTargetVelocity when it has
+    /// one. Returns the pivot.
+    pub(crate) fn look_ahead(
+        &mut self,
+        look_ahead: &LookAhead,
+        target_point: Vec3,
+        velocity: Option<Vec3>,
+        dt: f32,
+    ) -> Vec3 {
+        let last_target_point = self.last_pivot_target.replace(target_point);
+
+        let mut lead = Vec3::ZERO;
+        if look_ahead.time > 0.0 && !self.snap {
+            let velocity = velocity.unwrap_or_else(|| {
+                last_target_point
+                    .filter(|_| dt > 0.0)
+                    .map_or(Vec3::ZERO, |last| (target_point - last) / dt)
+            });
+            lead = velocity * look_ahead.time;
+            if !look_ahead.vertical {
+                lead.y = 0.0;
+            }
+            lead = lead.clamp_length_max(look_ahead.max_distance);
+        }
+        self.look_ahead =
+            look_ahead
+                .damping
+                .smooth(self.look_ahead, lead, &mut self.look_ahead_velocity, dt);
+
+        target_point + self.look_ahead
+    }
+}
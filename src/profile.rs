@@ -0,0 +1,456 @@
+use bevy::prelude::*;
+
+use crate::{FollowDamping, ThirdPersonCamera, ThirdPersonCameraState, time::CameraTime};
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_profiles);
+    }
+}
+
+/// A named set of camera settings for one camera mode, such as exploration, combat or vehicle.
+/// Profiles are activated through the CameraProfileStack component.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::CameraProfile;
+/// let vehicle = CameraProfile {
+///     radius: 8.0,
+///     max_radius: 12.0,
+///     fov: Some(70.0_f32.to_radians()),
+///     ..CameraProfile::new("vehicle")
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CameraProfile {
+    /// Used to find the profile in the stack, e.g. with `CameraProfileStack::remove`.
+    pub name: String,
+    /// The zoom radius the camera moves to.
+    /// Default is 2.25
+    pub radius: f32,
+    /// The minimum zoom radius.
+    /// Default is 1.5
+    pub min_radius: f32,
+    /// The maximum zoom radius.
+    /// Default is 3.0
+    pub max_radius: f32,
+    /// The offset of the camera, with a positive x towards the right shoulder. The shoulder swap
+    /// is kept. Vec2::ZERO disables the offset.
+    /// Default is Vec2::ZERO
+    pub offset: Vec2,
+    /// The vertical field of view in radians. None leaves the field of view untouched.
+    /// Default is None
+    pub fov: Option<f32>,
+    /// Default is -89 degrees
+    pub min_pitch: f32,
+    /// Default is 89 degrees
+    pub max_pitch: f32,
+    /// The mouse sensitivity, in degrees per mouse count.
+    /// Default is Vec2::new(0.15, 0.15)
+    pub sensitivity: Vec2,
+    /// The gamepad sensitivity, in degrees per second.
+    /// Default is Vec2::new(120.0, 60.0)
+    pub gamepad_sensitivity: Vec2,
+    /// Can't be blended, so it switches at the start of a transition.
+    /// Default is FollowDamping::default()
+    pub damping: FollowDamping,
+}
+
+impl Default for CameraProfile {
+    fn default() -> Self {
+        Self {
+            name: String::from("default"),
+            radius: 2.25,
+            min_radius: 1.5,
+            max_radius: 3.0,
+            offset: Vec2::ZERO,
+            fov: None,
+            min_pitch: -89.0_f32.to_radians(),
+            max_pitch: 89.0_f32.to_radians(),
+            sensitivity: Vec2::new(0.15, 0.15),
+            gamepad_sensitivity: Vec2::new(120.0, 60.0),
+            damping: FollowDamping::default(),
+        }
+    }
+}
+
+impl CameraProfile {
+    /// A profile with the default settings and the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..default()
+        }
+    }
+
+    /// Captures the current settings of a camera.
+    pub fn from_camera(
+        name: impl Into<String>,
+        cam: &ThirdPersonCamera,
+        projection: Option<&Projection>,
+    ) -> Self {
+        let offset = if cam.offset_enabled {
+            Vec2::new(cam.offset.offset_copy.0, cam.offset.offset_copy.1)
+        } else {
+            Vec2::ZERO
+        };
+        let fov = match projection {
+            Some(Projection::Perspective(perspective)) => Some(perspective.fov),
+            _ => None,
+        };
+        Self {
+            name: name.into(),
+            radius: cam.zoom.radius_copy.unwrap_or(cam.zoom.radius),
+            min_radius: cam.zoom.min,
+            max_radius: cam.zoom.max,
+            offset,
+            fov,
+            min_pitch: cam.min_pitch,
+            max_pitch: cam.max_pitch,
+            sensitivity: cam.sensitivity,
+            gamepad_sensitivity: cam.gamepad_settings.sensitivity,
+            damping: cam.damping,
+        }
+    }
+}
+
+/// How the camera blends from one profile to another.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProfileTransition {
+    /// The blend time in seconds. 0.0 switches instantly.
+    /// Default is 0.5
+    pub duration: f32,
+    /// Default is EaseFunction::CubicInOut
+    pub ease: EaseFunction,
+}
+
+impl Default for ProfileTransition {
+    fn default() -> Self {
+        Self {
+            duration: 0.5,
+            ease: EaseFunction::CubicInOut,
+        }
+    }
+}
+
+impl ProfileTransition {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ..default()
+        }
+    }
+
+    /// Switches to the new profile without blending.
+    pub fn instant() -> Self {
+        Self::new(0.0)
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+// a blend from the camera's settings at the start of the transition to the active profile
+struct Blend {
+    transition: ProfileTransition,
+    // captured from the camera on the first frame of the blend
+    from: Option<CameraProfile>,
+    elapsed: f32,
+}
+
+/// A stack of camera profiles. The profile on top of the stack is active, and the camera blends
+/// to it whenever it changes. Temporary modes, such as aiming or a cinematic, can be pushed and
+/// popped, falling back to the profile below. When the stack is empty the camera falls back to
+/// the base profile, which is captured from the camera's own settings unless it is set.
+///
+/// Zoom input keeps working on top of the active profile; profiles are only applied while blending.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{CameraProfile, CameraProfileStack, ProfileTransition};
+/// fn enter_combat(mut stack_q: Query<&mut CameraProfileStack>) {
+///     let combat = CameraProfile {
+///         radius: 2.0,
+///         offset: Vec2::new(0.6, 0.3),
+///         ..CameraProfile::new("combat")
+///     };
+///     for mut stack in stack_q.iter_mut() {
+///         stack.push(combat.clone(), ProfileTransition::new(0.3));
+///     }
+/// }
+///
+/// fn leave_combat(mut stack_q: Query<&mut CameraProfileStack>) {
+///     for mut stack in stack_q.iter_mut() {
+///         stack.remove("combat", ProfileTransition::default());
+///     }
+/// }
+/// ```
+#[derive(Component, Default)]
+pub struct CameraProfileStack {
+    base: Option<CameraProfile>,
+    stack: Vec<CameraProfile>,
+    blend: Option<Blend>,
+}
+
+impl CameraProfileStack {
+    /// Uses the given profile as the base, instead of the camera's own settings.
+    pub fn with_base(mut self, base: CameraProfile) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Replaces the base profile, blending to it if the stack is empty.
+    pub fn set_base(&mut self, base: CameraProfile, transition: ProfileTransition) {
+        self.base = Some(base);
+        if self.stack.is_empty() {
+            self.start(transition);
+        }
+    }
+
+    /// Pushes a profile on top of the stack and blends to it.
+    pub fn push(&mut self, profile: CameraProfile, transition: ProfileTransition) {
+        self.stack.push(profile);
+        self.start(transition);
+    }
+
+    /// Pops the profile on top of the stack and blends back to the one below.
+    pub fn pop(&mut self, transition: ProfileTransition) -> Option<CameraProfile> {
+        let profile = self.stack.pop()?;
+        self.start(transition);
+        Some(profile)
+    }
+
+    /// Removes the topmost profile with the given name. Only blends when the removed profile was active.
+    pub fn remove(&mut self, name: &str, transition: ProfileTransition) -> Option<CameraProfile> {
+        let index = self
+            .stack
+            .iter()
+            .rposition(|profile| profile.name == name)?;
+        if index == self.stack.len() - 1 {
+            return self.pop(transition);
+        }
+        Some(self.stack.remove(index))
+    }
+
+    /// Pops every profile and blends back to the base profile.
+    pub fn clear(&mut self, transition: ProfileTransition) {
+        if !self.stack.is_empty() {
+            self.stack.clear();
+            self.start(transition);
+        }
+    }
+
+    /// The active profile. None while the stack is empty and the base profile hasn't been captured yet.
+    pub fn active(&self) -> Option<&CameraProfile> {
+        self.stack.last().or(self.base.as_ref())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.stack.iter().any(|profile| profile.name == name)
+    }
+
+    pub fn is_blending(&self) -> bool {
+        self.blend.is_some()
+    }
+
+    fn start(&mut self, transition: ProfileTransition) {
+        self.blend = Some(Blend {
+            transition,
+            from: None,
+            elapsed: 0.0,
+        });
+    }
+}
+
+fn apply_profiles(
+    time: CameraTime,
+    mut cam_q: Query<(
+        &mut ThirdPersonCamera,
+        &mut CameraProfileStack,
+        &mut ThirdPersonCameraState,
+        Option<&mut Projection>,
+    )>,
+) {
+    for (mut cam, mut profiles, mut state, mut projection) in cam_q.iter_mut() {
+        let profiles = &mut *profiles;
+        if profiles.base.is_none() {
+            profiles.base = Some(CameraProfile::from_camera(
+                "base",
+                &cam,
+                projection.as_deref(),
+            ));
+        }
+
+        let Some(blend) = profiles.blend.as_mut() else {
+            continue;
+        };
+        let Some(to) = profiles.stack.last().or(profiles.base.as_ref()) else {
+            continue;
+        };
+        let from = blend.from.get_or_insert_with(|| {
+            CameraProfile::from_camera("blend", &cam, projection.as_deref())
+        });
+
+        blend.elapsed += time.delta_secs(&state);
+        let progress = (blend.elapsed / blend.transition.duration.max(f32::EPSILON)).min(1.0);
+        let t = blend.transition.ease.sample_clamped(progress);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+        cam.damping = to.damping;
+        cam.zoom.min = lerp(from.min_radius, to.min_radius);
+        cam.zoom.max = lerp(from.max_radius, to.max_radius);
+        let radius = lerp(from.radius, to.radius).clamp(cam.zoom.min, cam.zoom.max);
+        // while aiming, the radius is restored to the profile's radius afterwards
+        match cam.zoom.radius_copy.as_mut() {
+            Some(radius_copy) => *radius_copy = radius,
+            None => cam.zoom.radius = radius,
+        }
+
+        let offset = from.offset.lerp(to.offset, t);
+        let side = if cam.offset.is_transitioning {
+            -1.0
+        } else {
+            1.0
+        };
+        cam.offset.offset_copy = (offset.x, offset.y);
+        cam.offset.offset = (offset.x * side, offset.y);
+        cam.offset_enabled = to.offset != Vec2::ZERO || (progress < 1.0 && offset != Vec2::ZERO);
+
+        cam.min_pitch = lerp(from.min_pitch, to.min_pitch);
+        cam.max_pitch = lerp(from.max_pitch, to.max_pitch);
+        cam.sensitivity = from.sensitivity.lerp(to.sensitivity, t);
+        cam.gamepad_settings.sensitivity = from.gamepad_sensitivity.lerp(to.gamepad_sensitivity, t);
+
+        if let (Some(from_fov), Some(to_fov)) = (from.fov, to.fov)
+            && let Some(Projection::Perspective(perspective)) = projection.as_deref_mut()
+        {
+            // while aiming, the field of view is restored to the profile's afterwards
+            let fov = lerp(from_fov, to_fov);
+            match state.base_fov.as_mut() {
+                Some(base_fov) => *base_fov = fov,
+                None => perspective.fov = fov,
+            }
+        }
+
+        if progress >= 1.0 {
+            profiles.blend = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(stack: &CameraProfileStack) -> Vec<&str> {
+        stack
+            .stack
+            .iter()
+            .map(|profile| profile.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn push_and_pop_blend_to_the_top_profile() {
+        let mut stack = CameraProfileStack::default();
+        assert!(stack.active().is_none());
+
+        stack.push(CameraProfile::new("combat"), ProfileTransition::default());
+        stack.push(CameraProfile::new("aim"), ProfileTransition::default());
+        assert_eq!(stack.active().map(|p| p.name.as_str()), Some("aim"));
+        assert!(stack.is_blending());
+
+        stack.blend = None;
+        assert_eq!(
+            stack.pop(ProfileTransition::default()).map(|p| p.name),
+            Some("aim".into())
+        );
+        assert_eq!(stack.active().map(|p| p.name.as_str()), Some("combat"));
+        assert!(stack.is_blending());
+    }
+
+    #[test]
+    fn pop_on_an_empty_stack_does_nothing() {
+        let mut stack = CameraProfileStack::default();
+        assert!(stack.pop(ProfileTransition::default()).is_none());
+        assert!(!stack.is_blending());
+    }
+
+    #[test]
+    fn remove_only_blends_when_the_active_profile_is_removed() {
+        let mut stack = CameraProfileStack::default();
+        stack.push(CameraProfile::new("combat"), ProfileTransition::default());
+        stack.push(CameraProfile::new("vehicle"), ProfileTransition::default());
+        stack.blend = None;
+
+        assert!(
+            stack
+                .remove("combat", ProfileTransition::default())
+                .is_some()
+        );
+        assert_eq!(names(&stack), ["vehicle"]);
+        assert!(!stack.is_blending());
+
+        assert!(
+            stack
+                .remove("missing", ProfileTransition::default())
+                .is_none()
+        );
+        assert!(
+            stack
+                .remove("vehicle", ProfileTransition::default())
+                .is_some()
+        );
+        assert!(stack.is_blending());
+    }
+
+    #[test]
+    fn remove_takes_the_topmost_profile_with_the_name() {
+        let mut stack = CameraProfileStack::default();
+        stack.push(CameraProfile::new("combat"), ProfileTransition::default());
+        stack.push(CameraProfile::new("aim"), ProfileTransition::default());
+        stack.push(CameraProfile::new("combat"), ProfileTransition::default());
+
+        stack.remove("combat", ProfileTransition::default());
+        assert_eq!(names(&stack), ["combat", "aim"]);
+        assert!(stack.contains("combat"));
+    }
+
+    #[test]
+    fn clear_falls_back_to_the_base_profile() {
+        let mut stack = CameraProfileStack::default().with_base(CameraProfile::new("base"));
+        stack.clear(ProfileTransition::default());
+        assert!(!stack.is_blending());
+
+        stack.push(CameraProfile::new("combat"), ProfileTransition::default());
+        stack.push(CameraProfile::new("aim"), ProfileTransition::default());
+        stack.blend = None;
+        stack.clear(ProfileTransition::default());
+        assert!(names(&stack).is_empty());
+        assert_eq!(stack.active().map(|p| p.name.as_str()), Some("base"));
+        assert!(stack.is_blending());
+    }
+
+    #[test]
+    fn set_base_only_blends_when_the_stack_is_empty() {
+        let mut stack = CameraProfileStack::default();
+        stack.push(CameraProfile::new("combat"), ProfileTransition::default());
+        stack.blend = None;
+        stack.set_base(CameraProfile::new("base"), ProfileTransition::default());
+        assert!(!stack.is_blending());
+
+        stack.pop(ProfileTransition::default());
+        stack.blend = None;
+        stack.set_base(CameraProfile::new("calm"), ProfileTransition::default());
+        assert!(stack.is_blending());
+        assert_eq!(stack.active().map(|p| p.name.as_str()), Some("calm"));
+    }
+}
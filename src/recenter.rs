@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCamera, ThirdPersonCameraState, damping::wrap_angle, input::CameraInput, target_yaw,
+};
+
+/// Swings the camera back behind the target, in the direction the target is facing. The recenter
+/// action always triggers a recenter, auto recentering kicks in after a period without orbit input.
+/// Orbit input always cancels a recenter.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{Recenter, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera {
+///             recenter: Recenter {
+///                 auto_enabled: true,
+///                 only_while_moving: true,
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Recenter {
+    /// Flag to indicate if the camera recenters on its own after a period without orbit input.
+    /// Default is false
+    pub auto_enabled: bool,
+    /// The time in seconds without orbit input before auto recentering starts.
+    /// Default is 2.0
+    pub delay: f32,
+    /// The speed at which the camera swings back. Higher values are snappier.
+    /// Default is 4.0
+    pub speed: f32,
+    /// Flag to indicate if auto recentering only happens while the target is moving.
+    /// Default is false
+    pub only_while_moving: bool,
+    /// The pitch, in radians, to return to while recentering. If None, the pitch is left untouched.
+    /// Default is None
+    pub pitch: Option<f32>,
+}
+
+impl Default for Recenter {
+    fn default() -> Self {
+        Self {
+            auto_enabled: false,
+            delay: 2.0,
+            speed: 4.0,
+            only_while_moving: false,
+            pitch: None,
+        }
+    }
+}
+
+/// Restricts the horizontal orbit to an arc behind the target. Angles are in radians, relative to
+/// directly behind the target. Positive angles swing the camera around to the target's right.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_third_person_camera::YawLimits;
+/// // ±70 degrees, slowing down over the last 15 degrees
+/// let limits = YawLimits::symmetric(70.0_f32.to_radians()).with_soft_zone(15.0_f32.to_radians());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct YawLimits {
+    pub min: f32,
+    pub max: f32,
+    /// The width of the zone near each limit in which orbit sensitivity falls off to zero.
+    /// 0.0 is a hard stop.
+    pub soft_zone: f32,
+}
+
+impl YawLimits {
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            soft_zone: 0.0,
+        }
+    }
+
+    /// Limits the orbit to `angle` to either side of the target.
+    pub fn symmetric(angle: f32) -> Self {
+        Self::new(-angle, angle)
+    }
+
+    pub fn with_soft_zone(mut self, soft_zone: f32) -> Self {
+        self.soft_zone = soft_zone;
+        self
+    }
+
+    // returns the new relative yaw after rotating by delta
+    pub(crate) fn apply(&self, relative_yaw: f32, delta: f32) -> f32 {
+        let max = self.max.max(self.min);
+
+        // slow down the rotation when moving into the soft zone of a limit
+        let distance_to_limit = if delta > 0.0 {
+            max - relative_yaw
+        } else {
+            relative_yaw - self.min
+        };
+        let falloff = if self.soft_zone > 0.0 {
+            (distance_to_limit / self.soft_zone).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        (relative_yaw + delta * falloff).clamp(self.min, max)
+    }
+}
+
+impl ThirdPersonCameraState {
+    /// Swings the camera back behind the target when requested, or after a period without orbit input.
+    /// Returns the yaw & pitch to rotate by this frame.
+    pub(crate) fn recenter(
+        &mut self,
+        cam: &ThirdPersonCamera,
+        input: &CameraInput,
+        target: &GlobalTransform,
+        orbit_delta: Vec2,
+        dt: f32,
+    ) -> Vec2 {
+        let recenter = &cam.recenter;
+
+        if orbit_delta != Vec2::ZERO {
+            self.idle_secs = 0.0;
+            self.recentering = false;
+        } else {
+            self.idle_secs += dt;
+        }
+
+        if input.recenter {
+            self.recentering = true;
+        }
+
+        let is_moving = self
+            .last_target_translation
+            .is_some_and(|last| dt > 0.0 && last.distance(target.translation()) / dt > 0.1);
+        self.last_target_translation = Some(target.translation());
+
+        let auto_recenter = recenter.auto_enabled
+            && self.idle_secs >= recenter.delay
+            && (is_moving || !recenter.only_while_moving);
+
+        if !self.recentering && !auto_recenter {
+            return Vec2::ZERO;
+        }
+
+        let factor = 1.0 - (-recenter.speed * dt).exp();
+        let delta_yaw = wrap_angle(target_yaw(target) - self.yaw) * factor;
+        let delta_pitch = recenter
+            .pitch
+            .map_or(0.0, |pitch| (pitch - self.pitch) * factor);
+
+        // a manual recenter stops once the camera is behind the target
+        if delta_yaw.abs() < 0.001 && delta_pitch.abs() < 0.001 {
+            self.recentering = false;
+        }
+
+        Vec2::new(delta_yaw, delta_pitch)
+    }
+}
@@ -0,0 +1,255 @@
+use bevy::prelude::*;
+
+use crate::{
+    CameraSyncSystems, ThirdPersonCamera, ThirdPersonCameraState, ThirdPersonCameraTarget,
+};
+
+pub struct ShakePlugin;
+
+impl Plugin for ShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ShakeCamera>()
+            .add_systems(Update, add_trauma)
+            .add_systems(PostUpdate, shake.in_set(CameraSyncSystems::Effects));
+    }
+}
+
+/// Shakes the camera based on its trauma. Trauma is added with `add_trauma` or the ShakeCamera
+/// message, and decays over time. The shake is applied on top of the camera's transform and
+/// never changes the orbit rotation.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::{CameraShake, ThirdPersonCamera};
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         ThirdPersonCamera::default(),
+///         CameraShake {
+///             max_rotation: 3.0_f32.to_radians(),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Shaken)]
+pub struct CameraShake {
+    /// The current trauma, in the range 0..1.
+    /// Default is 0.0
+    pub trauma: f32,
+    /// The amount of trauma removed per second.
+    /// Default is 1.0
+    pub decay: f32,
+    /// The shake strength is trauma raised to this power. Higher values make small amounts of trauma subtler.
+    /// Default is 2.0
+    pub trauma_exponent: f32,
+    /// The maximum x/y offset of the camera, relative to the camera's view.
+    /// Default is Vec2::new(0.15, 0.15)
+    pub max_offset: Vec2,
+    /// The maximum yaw, pitch & roll of the camera, in radians.
+    /// Default is 5 degrees
+    pub max_rotation: f32,
+    /// How fast the shake moves. Higher values shake more violently.
+    /// Default is 15.0
+    pub frequency: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            trauma_exponent: 2.0,
+            max_offset: Vec2::new(0.15, 0.15),
+            max_rotation: 5.0_f32.to_radians(),
+            frequency: 15.0,
+        }
+    }
+}
+
+impl CameraShake {
+    /// Adds trauma, keeping the total trauma within 0..1.
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+}
+
+/// Adds trauma to every camera with a CameraShake component. When a `source` is given, the trauma
+/// falls off with the distance between the source and the camera's target, reaching zero at `radius`.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_third_person_camera::ShakeCamera;
+/// fn explode(mut shake: MessageWriter<ShakeCamera>) {
+///     shake.write(ShakeCamera::at(0.8, Vec3::new(3.0, 0.0, 2.0), 10.0));
+/// }
+/// ```
+#[derive(Message, Clone, Copy, Debug)]
+pub struct ShakeCamera {
+    pub trauma: f32,
+    pub source: Option<Vec3>,
+    pub radius: f32,
+}
+
+impl ShakeCamera {
+    /// Shakes every camera by the same amount.
+    pub fn new(trauma: f32) -> Self {
+        Self {
+            trauma,
+            source: None,
+            radius: 0.0,
+        }
+    }
+
+    /// Shakes cameras whose target is within `radius` of `source`, less the further away they are.
+    pub fn at(trauma: f32, source: Vec3, radius: f32) -> Self {
+        Self {
+            trauma,
+            source: Some(source),
+            radius,
+        }
+    }
+}
+
+// the camera transform before & after the last shake, to undo it when the transform isn't rebuilt
+#[derive(Component, Default)]
+struct Shaken(Option<(Transform, Transform)>);
+
+fn add_trauma(
+    mut shake_evr: MessageReader<ShakeCamera>,
+    target_q: Query<Entity, With<ThirdPersonCameraTarget>>,
+    player_q: Query<&GlobalTransform, Without<ThirdPersonCamera>>,
+    mut cam_q: Query<(&ThirdPersonCamera, &mut CameraShake)>,
+) {
+    for ev in shake_evr.read() {
+        for (cam, mut shake) in cam_q.iter_mut() {
+            let falloff = match ev.source {
+                None => 1.0,
+                Some(source) => {
+                    let Some(player) = cam
+                        .target_entity(&target_q)
+                        .and_then(|target| player_q.get(target).ok())
+                    else {
+                        continue;
+                    };
+                    let distance = source.distance(player.translation());
+                    1.0 - (distance / ev.radius.max(f32::EPSILON)).clamp(0.0, 1.0)
+                }
+            };
+            shake.add_trauma(ev.trauma * falloff);
+        }
+    }
+}
+
+fn shake(
+    time: Res<Time>,
+    mut cam_q: Query<(
+        &mut CameraShake,
+        &mut Shaken,
+        &mut Transform,
+        Option<&ThirdPersonCameraState>,
+    )>,
+) {
+    let t = time.elapsed_secs();
+    for (mut shake, mut shaken, mut cam_transform, state) in cam_q.iter_mut() {
+        // undo the last shake, unless the camera transform was rebuilt since
+        if let Some((unshaken, shaken_transform)) = shaken.0.take()
+            && *cam_transform == shaken_transform
+        {
+            *cam_transform = unshaken;
+        }
+
+        // photo mode freezes the game, so the shake would freeze in place
+        if state.is_some_and(|state| state.is_in_photo_mode()) {
+            continue;
+        }
+
+        shake.trauma = (shake.trauma - shake.decay * time.delta_secs()).max(0.0);
+        if shake.trauma <= 0.0 {
+            continue;
+        }
+
+        let strength = shake.trauma.powf(shake.trauma_exponent);
+        let t = t * shake.frequency;
+
+        let offset = Vec3::new(
+            shake.max_offset.x * noise(0, t),
+            shake.max_offset.y * noise(1, t),
+            0.0,
+        ) * strength;
+        let rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            shake.max_rotation * strength * noise(2, t),
+            shake.max_rotation * strength * noise(3, t),
+            shake.max_rotation * strength * noise(4, t),
+        );
+
+        let unshaken = *cam_transform;
+        let view_offset = cam_transform.rotation * offset;
+        cam_transform.translation += view_offset;
+        cam_transform.rotation *= rotation;
+        shaken.0 = Some((unshaken, *cam_transform));
+    }
+}
+
+// smooth 1D value noise in the range -1..1
+fn noise(seed: u32, t: f32) -> f32 {
+    let i = t.floor();
+    let f = t - i;
+    let a = hash(seed, i as i32);
+    let b = hash(seed, i as i32 + 1);
+    let u = f * f * (3.0 - 2.0 * f);
+    a + (b - a) * u
+}
+
+fn hash(seed: u32, i: i32) -> f32 {
+    let mut x = (i as u32).wrapping_mul(0x27d4_eb2d) ^ seed.wrapping_mul(0x1656_67b1);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x2c1b_3c6d);
+    x ^= x >> 12;
+    x as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noise_stays_within_range() {
+        for i in 0..1000 {
+            let t = i as f32 * 0.037 - 10.0;
+            for seed in 0..5 {
+                assert!((-1.0..=1.0).contains(&noise(seed, t)));
+            }
+        }
+    }
+
+    #[test]
+    fn noise_is_continuous() {
+        for i in 0..1000 {
+            let t = i as f32 * 0.01;
+            assert!((noise(0, t + 0.001) - noise(0, t)).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn noise_is_deterministic_and_differs_per_seed() {
+        assert_eq!(noise(3, 1.25), noise(3, 1.25));
+        assert_ne!(noise(0, 1.5), noise(1, 1.5));
+    }
+
+    #[test]
+    fn trauma_is_clamped() {
+        let mut shake = CameraShake::default();
+        shake.add_trauma(0.7);
+        shake.add_trauma(0.7);
+        assert_eq!(shake.trauma, 1.0);
+        shake.add_trauma(-3.0);
+        assert_eq!(shake.trauma, 0.0);
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::ThirdPersonCameraState;
+
+/// The clock of the camera systems: the default clock, or real time while the camera is in photo
+/// mode, which pauses virtual time, or flies freely, so debug & replay cameras keep moving while
+/// the game is paused or slowed down.
+#[derive(SystemParam)]
+pub(crate) struct CameraTime<'w> {
+    time: Res<'w, Time>,
+    real: Res<'w, Time<Real>>,
+}
+
+impl CameraTime<'_> {
+    pub(crate) fn delta_secs(&self, state: &ThirdPersonCameraState) -> f32 {
+        if uses_real_time(state) {
+            self.real.delta_secs()
+        } else {
+            self.time.delta_secs()
+        }
+    }
+
+    // the delta of things shared by every camera, such as fades: real time while any camera uses it
+    pub(crate) fn shared_delta_secs<'a>(
+        &self,
+        mut states: impl Iterator<Item = &'a ThirdPersonCameraState>,
+    ) -> f32 {
+        if states.any(uses_real_time) {
+            self.real.delta_secs()
+        } else {
+            self.time.delta_secs()
+        }
+    }
+}
+
+fn uses_real_time(state: &ThirdPersonCameraState) -> bool {
+    state.is_in_photo_mode() || state.free_fly
+}